@@ -0,0 +1,31 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use fallible_map::{FallibleMapF32Ext, FallibleMapIteratorExt};
+
+fn scale(x: f32) -> Result<f32, &'static str> {
+    if x.is_nan() {
+        Err("NaN")
+    } else {
+        Ok(x * 2.5)
+    }
+}
+
+fn bench_f32(c: &mut Criterion) {
+    let values: Vec<f32> = (0..100_000).map(|x| x as f32).collect();
+
+    c.bench_function("try_map_f32_chunks", |b| {
+        b.iter(|| black_box(&values).try_map_f32_chunks(scale))
+    });
+
+    c.bench_function("try_map_scalar", |b| {
+        b.iter(|| {
+            black_box(&values)
+                .iter()
+                .copied()
+                .try_map(scale)
+                .collect::<Result<Vec<f32>, _>>()
+        })
+    });
+}
+
+criterion_group!(benches, bench_f32);
+criterion_main!(benches);
@@ -0,0 +1,149 @@
+//! `fallible_map_derive` provides the `FallibleMap` derive macro, generating
+//! fallible mapping over the `Option` fields of a struct.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Fields, GenericArgument, PathArguments, Type};
+
+/// Derive a `try_map_fields` method for a struct with `Option` fields.
+///
+/// For every field of type `Option<T>`, in declaration order, the generated
+/// method takes a closure `FnOnce(T) -> Result<T, E>`. Each closure is applied
+/// to its field when the field is `Some`, while `None` fields and non-`Option`
+/// fields are moved over unchanged. The struct is rebuilt from the mapped
+/// fields, short-circuiting on the first error.
+///
+/// Closure parameters are named after the fields for named structs, and
+/// `f0`, `f1`, ... (by `Option` field position) for tuple structs.
+///
+/// # Example
+///
+/// ```ignore
+/// #[derive(FallibleMap)]
+/// struct Config {
+///     name: Option<String>,
+///     port: Option<u16>,
+/// }
+///
+/// // Generates:
+/// impl Config {
+///     pub fn try_map_fields<__E, __F0, __F1>(self, name: __F0, port: __F1) -> Result<Self, __E>
+///     where
+///         __F0: FnOnce(String) -> Result<String, __E>,
+///         __F1: FnOnce(u16) -> Result<u16, __E>;
+/// }
+/// ```
+///
+/// Only fields whose type is literally written as `Option<T>` (or a path
+/// ending in `Option<T>`) are detected; type aliases are treated as plain fields.
+#[proc_macro_derive(FallibleMap)]
+pub fn derive_fallible_map(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "`FallibleMap` can only be derived for structs",
+            ))
+        }
+    };
+
+    let error = format_ident!("__E");
+    let value = Ident::new("__value", Span::mixed_site());
+    let mut params = Vec::new();
+    let mut generics = Vec::new();
+    let mut bounds = Vec::new();
+    let mut idents = Vec::new();
+    let mut bindings = Vec::new();
+    let mut values = Vec::new();
+
+    for (index, field) in fields.iter().enumerate() {
+        let binding = Ident::new(&format!("__field{}", index), Span::mixed_site());
+
+        match option_inner(&field.ty) {
+            Some(inner) => {
+                let position = params.len();
+                let param = match &field.ident {
+                    Some(ident) => ident.clone(),
+                    None => format_ident!("f{}", position),
+                };
+                let generic = format_ident!("__F{}", position);
+
+                bounds.push(quote! { #generic: ::core::ops::FnOnce(#inner) -> ::core::result::Result<#inner, #error> });
+                values.push(quote! {
+                    match #binding {
+                        ::core::option::Option::Some(#value) => ::core::option::Option::Some(#param(#value)?),
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    }
+                });
+                params.push(quote! { #param: #generic });
+                generics.push(generic);
+            }
+            None => values.push(quote! { #binding }),
+        }
+
+        idents.push(&field.ident);
+        bindings.push(binding);
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let (pattern, rebuilt) = match fields {
+        Fields::Named(_) => (
+            quote! { #name { #(#idents: #bindings),* } },
+            quote! { #name { #(#idents: #values),* } },
+        ),
+        Fields::Unnamed(_) => (
+            quote! { #name ( #(#bindings),* ) },
+            quote! { #name ( #(#values),* ) },
+        ),
+        Fields::Unit => (quote! { #name }, quote! { #name }),
+    };
+
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Attempt to map a function over each `Option` field, rebuilding the struct.
+            #[allow(clippy::too_many_arguments)]
+            pub fn try_map_fields<#error, #(#generics),*>(self, #(#params),*) -> ::core::result::Result<Self, #error>
+            where
+                #(#bounds,)*
+            {
+                let #pattern = self;
+
+                ::core::result::Result::Ok(#rebuilt)
+            }
+        }
+    })
+}
+
+/// Return the `T` of a type written as `Option<T>`.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let path = match ty {
+        Type::Path(ty) if ty.qself.is_none() => &ty.path,
+        _ => return None,
+    };
+
+    let segment = path.segments.last()?;
+
+    if segment.ident != "Option" {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(inner) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
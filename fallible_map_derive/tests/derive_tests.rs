@@ -0,0 +1,92 @@
+use fallible_map_derive::FallibleMap;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(FallibleMap, Debug, PartialEq)]
+    struct Config {
+        name: Option<String>,
+        port: Option<u16>,
+    }
+
+    #[derive(FallibleMap, Debug, PartialEq)]
+    struct Pair(Option<i32>, Option<i32>);
+
+    #[test]
+    fn test_try_map_fields_named() {
+        let config = Config {
+            name: Some(" server ".to_string()),
+            port: None,
+        };
+
+        let result: Result<_, String> = config.try_map_fields(
+            |name| Ok(name.trim().to_string()),
+            |port| {
+                if port > 0 {
+                    Ok(port)
+                } else {
+                    Err("Invalid port".to_string())
+                }
+            },
+        );
+
+        assert_eq!(
+            result,
+            Ok(Config {
+                name: Some("server".to_string()),
+                port: None,
+            })
+        );
+
+        let config = Config {
+            name: Some("server".to_string()),
+            port: Some(0),
+        };
+
+        let result: Result<_, String> = config.try_map_fields(Ok, |port| {
+            if port > 0 {
+                Ok(port)
+            } else {
+                Err("Invalid port".to_string())
+            }
+        });
+
+        assert_eq!(result, Err("Invalid port".to_string()));
+    }
+
+    #[test]
+    fn test_try_map_fields_tuple() {
+        let mut calls = 0;
+
+        let result: Result<_, String> = Pair(Some(-1), Some(2)).try_map_fields(
+            |x| {
+                if x > 0 {
+                    Ok(x * 2)
+                } else {
+                    Err(format!("Failed to process {}", x))
+                }
+            },
+            |x| {
+                calls += 1;
+                Ok(x * 2)
+            },
+        );
+
+        assert_eq!(result, Err("Failed to process -1".to_string()));
+        assert_eq!(calls, 0);
+
+        let result: Result<_, String> =
+            Pair(Some(1), Some(2)).try_map_fields(|x| Ok(x * 2), |x| Ok(x * 2));
+
+        assert_eq!(result, Ok(Pair(Some(2), Some(4))));
+    }
+
+    #[test]
+    fn test_ui() {
+        let t = trybuild::TestCases::new();
+
+        t.pass("tests/ui/pass_*.rs");
+        t.compile_fail("tests/ui/fail_*.rs");
+    }
+}
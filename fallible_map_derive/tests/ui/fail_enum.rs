@@ -0,0 +1,8 @@
+use fallible_map_derive::FallibleMap;
+
+#[derive(FallibleMap)]
+enum Shape {
+    Circle(Option<f64>),
+}
+
+fn main() {}
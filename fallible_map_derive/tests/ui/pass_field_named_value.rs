@@ -0,0 +1,22 @@
+use fallible_map_derive::FallibleMap;
+
+#[derive(FallibleMap)]
+struct Internals {
+    __value: Option<i32>,
+    __field0: Option<i32>,
+}
+
+fn main() {
+    let internals = Internals {
+        __value: Some(1),
+        __field0: Some(2),
+    };
+
+    let result: Result<Internals, String> =
+        internals.try_map_fields(|value| Ok(value + 1), |field| Ok(field * 10));
+
+    let internals = result.unwrap();
+
+    assert_eq!(internals.__value, Some(2));
+    assert_eq!(internals.__field0, Some(20));
+}
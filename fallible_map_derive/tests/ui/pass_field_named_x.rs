@@ -0,0 +1,18 @@
+use fallible_map_derive::FallibleMap;
+
+#[derive(FallibleMap)]
+struct Point {
+    x: Option<i32>,
+    y: Option<i32>,
+}
+
+fn main() {
+    let point = Point { x: Some(1), y: None };
+
+    let result: Result<Point, String> = point.try_map_fields(|x| Ok(x + 1), |y| Ok(y - 1));
+
+    let point = result.unwrap();
+
+    assert_eq!(point.x, Some(2));
+    assert_eq!(point.y, None);
+}
@@ -0,0 +1,27 @@
+use fallible_map_derive::FallibleMap;
+
+#[derive(FallibleMap)]
+struct Record<T> {
+    id: u32,
+    label: Option<String>,
+    value: Option<T>,
+}
+
+fn main() {
+    let record = Record {
+        id: 1,
+        label: Some("label".to_string()),
+        value: Some(2.5),
+    };
+
+    let result: Result<Record<f64>, String> = record.try_map_fields(
+        |label| Ok(label.to_uppercase()),
+        |value| if value >= 0.0 { Ok(value) } else { Err("Negative value".to_string()) },
+    );
+
+    let record = result.unwrap();
+
+    assert_eq!(record.id, 1);
+    assert_eq!(record.label.as_deref(), Some("LABEL"));
+    assert_eq!(record.value, Some(2.5));
+}
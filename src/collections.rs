@@ -0,0 +1,188 @@
+//! Fallible operations over collections, mutating them in place or rebuilding them.
+
+use alloc::vec::Vec;
+#[cfg(any(feature = "std", feature = "indexmap"))]
+use core::hash::BuildHasher;
+#[cfg(feature = "indexmap")]
+use core::hash::Hash;
+#[cfg(feature = "indexmap")]
+use indexmap::IndexMap;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// Extend `Vec` with fallible in-place methods.
+///
+/// # Type Parameters
+///
+/// - `T`: The vector's element type
+pub trait FallibleVecExt<T> {
+    /// Retain only the elements for which a fallible predicate returns `Ok(true)`.
+    ///
+    /// Elements are visited in order, and the retained ones keep their order.
+    /// On error, the elements already rejected are removed, while the failing
+    /// element and every element after it are kept, so the vector holds the
+    /// retained prefix followed by the unprocessed remainder.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes a reference to an element and returns a `Result<bool, E>`.
+    ///
+    /// # Returns
+    ///
+    /// An empty `Result`, or the first error `E`.
+    fn try_retain<F, E>(&mut self, f: F) -> Result<(), E>
+    where
+        F: FnMut(&T) -> Result<bool, E>;
+}
+
+/// Implementation of `FallibleVecExt` for `Vec`.
+impl<T> FallibleVecExt<T> for Vec<T> {
+    fn try_retain<F, E>(&mut self, mut f: F) -> Result<(), E>
+    where
+        F: FnMut(&T) -> Result<bool, E>,
+    {
+        let mut kept = 0;
+        let mut index = 0;
+
+        let result = loop {
+            if index == self.len() {
+                break Ok(());
+            }
+
+            match f(&self[index]) {
+                Ok(true) => {
+                    self.swap(kept, index);
+                    kept += 1;
+                }
+                Ok(false) => {}
+                Err(e) => break Err(e),
+            }
+
+            index += 1;
+        };
+
+        self.drain(kept..index);
+
+        result
+    }
+}
+
+/// Extend `HashMap` with fallible in-place methods.
+///
+/// # Type Parameters
+///
+/// - `K`: The map's key type
+/// - `V`: The map's value type
+#[cfg(feature = "std")]
+pub trait FallibleHashMapExt<K, V> {
+    /// Apply a fallible function to each value in place, stopping at the first error.
+    ///
+    /// Values mutated before the error keep their changes. Since the iteration
+    /// order of a `HashMap` is unspecified, so is which values were mutated.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes a mutable reference to a value and returns a `Result<(), E>`.
+    ///
+    /// # Returns
+    ///
+    /// An empty `Result`, or the first error `E`.
+    fn try_map_values_in_place<F, E>(&mut self, f: F) -> Result<(), E>
+    where
+        F: FnMut(&mut V) -> Result<(), E>;
+
+    /// Retain only the entries for which a fallible predicate returns `Ok(true)`.
+    ///
+    /// On error, the entries already rejected are removed and every other
+    /// entry is kept, including the failing one. Since the iteration order of a
+    /// `HashMap` is unspecified, so is which entries were processed before the error.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes a key and a mutable reference to its value and returns a `Result<bool, E>`.
+    ///
+    /// # Returns
+    ///
+    /// An empty `Result`, or the first error `E`.
+    fn try_retain<F, E>(&mut self, f: F) -> Result<(), E>
+    where
+        F: FnMut(&K, &mut V) -> Result<bool, E>;
+}
+
+/// Implementation of `FallibleHashMapExt` for `HashMap`.
+#[cfg(feature = "std")]
+impl<K, V, S> FallibleHashMapExt<K, V> for HashMap<K, V, S>
+where
+    S: BuildHasher,
+{
+    fn try_map_values_in_place<F, E>(&mut self, f: F) -> Result<(), E>
+    where
+        F: FnMut(&mut V) -> Result<(), E>,
+    {
+        self.values_mut().try_for_each(f)
+    }
+
+    fn try_retain<F, E>(&mut self, mut f: F) -> Result<(), E>
+    where
+        F: FnMut(&K, &mut V) -> Result<bool, E>,
+    {
+        let mut error = None;
+
+        self.retain(|k, v| {
+            if error.is_some() {
+                return true;
+            }
+
+            f(k, v).unwrap_or_else(|e| {
+                error = Some(e);
+                true
+            })
+        });
+
+        match error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Extend `IndexMap` with fallible methods preserving insertion order.
+///
+/// # Type Parameters
+///
+/// - `K`: The map's key type
+/// - `V`: The map's value type
+/// - `S`: The map's hasher builder
+#[cfg(feature = "indexmap")]
+pub trait FallibleIndexMapExt<K, V, S> {
+    /// Attempt to map a function over each value, rebuilding the map in insertion order.
+    ///
+    /// Values are visited in insertion order, so the error returned is the one
+    /// of the earliest-inserted failing value.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes a value and returns a `Result<U, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an `IndexMap<K, U, S>`, or the first error `E`.
+    fn try_map_values<U, F, E>(self, f: F) -> Result<IndexMap<K, U, S>, E>
+    where
+        F: FnMut(V) -> Result<U, E>;
+}
+
+/// Implementation of `FallibleIndexMapExt` for `IndexMap`.
+#[cfg(feature = "indexmap")]
+impl<K, V, S> FallibleIndexMapExt<K, V, S> for IndexMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher + Default,
+{
+    fn try_map_values<U, F, E>(self, mut f: F) -> Result<IndexMap<K, U, S>, E>
+    where
+        F: FnMut(V) -> Result<U, E>,
+    {
+        self.into_iter().map(|(k, v)| Ok((k, f(v)?))).collect()
+    }
+}
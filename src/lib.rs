@@ -1,5 +1,21 @@
-/// `fallible_map_ext` provides utilities for fallible mapping over `Option`
-/// types and iterators, allowing the use of functions that can return `Result`s.
+//! `fallible_map_ext` provides utilities for fallible mapping over `Option`
+//! types and iterators, allowing the use of functions that can return `Result`s.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+
+pub mod collections;
+
+/// Derive a `try_map_fields` method mapping over the `Option` fields of a struct.
+#[cfg(feature = "derive")]
+pub use fallible_map_derive::FallibleMap;
 
 /// A helper trait to extract the inner value of an optional container.
 pub trait ExtractOption<T> {
@@ -65,6 +81,233 @@ pub trait FallibleMapExt<T, E> {
     fn try_and_then<F, U>(self, f: F) -> Result<Option<U>, E>
     where
         F: FnOnce(T) -> Result<Option<U>, E>;
+
+    /// Attempt to convert an optional value with `TryFrom`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an `Option<U>`, or the conversion error of `U: TryFrom<T>`.
+    fn try_map_try_from<U>(self) -> Result<Option<U>, E>
+    where
+        U: core::convert::TryFrom<T, Error = E>;
+
+    /// Validate an optional value without transforming it.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes a reference to the value and returns a `Result<(), E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the original `Option<T>`, or the validation error `E`.
+    fn try_validate<F>(self, f: F) -> Result<Option<T>, E>
+    where
+        F: FnOnce(&T) -> Result<(), E>;
+
+    /// Attempt to map an asynchronous function over an optional value.
+    ///
+    /// The function is called eagerly to build its future; for `None` the
+    /// returned future resolves immediately. The boxed future must be `Send`,
+    /// so the returned future can be spawned on a multi-threaded executor.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes a value of type `T` and returns a boxed future of `Result<U, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A future resolving to a `Result` containing an `Option<U>`, or an error `E`.
+    #[cfg(feature = "async")]
+    fn try_map_async_boxed<U, F>(
+        self,
+        f: F,
+    ) -> impl core::future::Future<Output = Result<Option<U>, E>> + Send
+    where
+        F: FnOnce(T) -> core::pin::Pin<Box<dyn core::future::Future<Output = Result<U, E>> + Send>>;
+
+    /// Attempt to map a function over an optional value, naming the output type first.
+    ///
+    /// Identical to `try_map`, but the output type comes first among the type
+    /// parameters, so it can be pinned with `try_map_to::<U, _>` when the function
+    /// is polymorphic in its output, such as `str::parse`.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes a value of type `T` and returns a `Result<U, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an `Option<U>`, or an error `E`.
+    fn try_map_to<U, F>(self, f: F) -> Result<Option<U>, E>
+    where
+        F: FnOnce(T) -> Result<U, E>;
+
+    /// Map a function returning an `Option` over an optional value, treating a missing result as an error.
+    ///
+    /// Useful for constructors such as `NonZeroU32::new`, which signal invalid
+    /// input with `None`.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes a value of type `T` and returns an `Option<U>`.
+    /// - `on_none`: A function that returns the error `E` when `f` returns `None`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an `Option<U>`, or the error `E` from `on_none`.
+    fn try_map_required<U, F, N>(self, f: F, on_none: N) -> Result<Option<U>, E>
+    where
+        F: FnOnce(T) -> Option<U>,
+        N: FnOnce() -> E;
+
+    /// Attempt to map a function returning a nested `Result` over an optional value, flattening it.
+    ///
+    /// Both layers must share the error type `E`; convert one of them with
+    /// `map_err` first if they differ.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes a value of type `T` and returns a `Result<Result<U, E>, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an `Option<U>`, or the outer or inner error `E`.
+    fn try_map_flat<U, F>(self, f: F) -> Result<Option<U>, E>
+    where
+        F: FnOnce(T) -> Result<Result<U, E>, E>;
+
+    /// Attempt to map a function over an optional value, returning an iterator over the result.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes a value of type `T` and returns a `Result<U, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an iterator yielding the mapped value, or nothing for `None`, or an error `E`.
+    fn try_map_iter<U, F>(self, f: F) -> Result<core::option::IntoIter<U>, E>
+    where
+        F: FnOnce(T) -> Result<U, E>;
+
+    /// Chain an infallible function returning an `Option` over an optional value.
+    ///
+    /// Never fails; the `Result` only keeps the shape of the other methods, so
+    /// the call fits into a fallible chain without wrapping the closure in `Ok`.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes a value of type `T` and returns an `Option<U>`.
+    ///
+    /// # Returns
+    ///
+    /// `Ok` with the `Option<U>` returned by `f`, or `Ok(None)` if there was no value.
+    fn try_and_then_opt<U, F>(self, f: F) -> Result<Option<U>, E>
+    where
+        F: FnOnce(T) -> Option<U>;
+
+    /// Attempt to map a function over the inner value of a nested optional value, such as `Option<Option<V>>`.
+    ///
+    /// `f` operates on the innermost value `V`, and is only called for `Some(Some(v))`;
+    /// both `Some(None)` and `None` collapse to `Ok(None)`.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes a value of type `V` and returns a `Result<U, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an `Option<U>`, or an error `E`.
+    fn try_map_flatten_inner<V, U, F>(self, f: F) -> Result<Option<U>, E>
+    where
+        T: ExtractOption<V>,
+        F: FnOnce(V) -> Result<U, E>;
+
+    /// Attempt to map a function pointer over an optional value.
+    ///
+    /// Behaves like `try_map`, for functions that capture nothing; the single
+    /// concrete function type avoids a monomorphization per closure.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function pointer that takes a value of type `T` and returns a `Result<U, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an `Option<U>`, or an error `E`.
+    fn try_map_fn<U>(self, f: fn(T) -> Result<U, E>) -> Result<Option<U>, E>;
+
+    /// Check the optional value with a fallible predicate.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes a reference to a value of type `T` and returns a `Result<bool, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the predicate's verdict, or `false` if there was no value, or an error `E`.
+    fn try_contains<F>(self, f: F) -> Result<bool, E>
+    where
+        F: FnOnce(&T) -> Result<bool, E>;
+
+    /// Convert the optional value to another type and back, checking that it survives unchanged.
+    ///
+    /// # Parameters
+    ///
+    /// - `to`: A function that takes a reference to a value of type `T` and returns a `Result<U, E>`.
+    /// - `from`: A function that takes a value of type `U` and returns a `Result<T, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the original `Option<T>`, or a `RoundtripError` if
+    /// a conversion failed or the converted-back value differs.
+    fn try_map_roundtrip<U, F, G>(self, to: F, from: G) -> Result<Option<T>, RoundtripError<E>>
+    where
+        F: FnOnce(&T) -> Result<U, E>,
+        G: FnOnce(U) -> Result<T, E>,
+        T: PartialEq;
+
+    /// Attempt to map a function over every element of an optional collection, such as `Option<Vec<T>>`.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes an element and returns a `Result<U, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an `Option<Vec<U>>`, or the first error `E`.
+    fn try_map_vec<U, F>(self, f: F) -> Result<Option<Vec<U>>, E>
+    where
+        T: IntoIterator,
+        F: FnMut(T::Item) -> Result<U, E>;
+
+    /// Attempt to map a function over an optional value, treating a missing value as an error.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes a value of type `T` and returns a `Result<U, E>`.
+    /// - `on_none`: A function that returns the error `E` when there is no value.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the mapped value `U`, or an error `E`.
+    fn try_map_or_abort<U, F, N>(self, f: F, on_none: N) -> Result<U, E>
+    where
+        F: FnOnce(T) -> Result<U, E>,
+        N: FnOnce() -> E;
+
+    /// Attempt to map a function returning a type convertible into a `Result` over an optional value.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes a value of type `T` and returns an `R: Into<Result<U, E>>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an `Option<U>`, or an error `E`.
+    fn try_map_into_result<U, R, F>(self, f: F) -> Result<Option<U>, E>
+    where
+        F: FnOnce(T) -> R,
+        R: Into<Result<U, E>>;
 }
 
 /// Implementation of `FallibleMapExt` for types implementing `ExtractOption`.
@@ -101,65 +344,4310 @@ where
             None => Ok(None),
         }
     }
-}
 
-/// A fallible map iterator that maps a function returning a `Result` over the elements of the underlying iterator.
-pub struct FallibleMapIterator<I, F, B, E> {
-    iter: I,
-    f: F,
-    _marker: std::marker::PhantomData<(B, E)>,
-}
+    fn try_map_try_from<U>(self) -> Result<Option<U>, E>
+    where
+        U: core::convert::TryFrom<T, Error = E>,
+    {
+        self.try_map(U::try_from)
+    }
 
-impl<I, F, B, E> FallibleMapIterator<I, F, B, E> {
-    pub fn new(iter: I, f: F) -> Self {
-        FallibleMapIterator {
-            iter,
-            f,
-            _marker: std::marker::PhantomData,
+    fn try_validate<F>(self, f: F) -> Result<Option<T>, E>
+    where
+        F: FnOnce(&T) -> Result<(), E>,
+    {
+        match self.extract() {
+            Some(x) => f(&x).map(|_| Some(x)),
+            None => Ok(None),
         }
     }
-}
 
-/// Implement `Iterator` for `FallibleMap` where the iterator item is a `Result`.
-impl<I, F, B, E> Iterator for FallibleMapIterator<I, F, B, E>
-where
-    I: Iterator,
-    F: FnMut(I::Item) -> Result<B, E>,
-{
-    type Item = Result<B, E>;
+    #[cfg(feature = "async")]
+    fn try_map_async_boxed<U, F>(
+        self,
+        f: F,
+    ) -> impl core::future::Future<Output = Result<Option<U>, E>> + Send
+    where
+        F: FnOnce(T) -> core::pin::Pin<Box<dyn core::future::Future<Output = Result<U, E>> + Send>>,
+    {
+        let future = self.extract().map(f);
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.iter.next().map(&mut self.f)
+        async move {
+            match future {
+                Some(future) => future.await.map(Some),
+                None => Ok(None),
+            }
+        }
+    }
+
+    fn try_map_to<U, F>(self, f: F) -> Result<Option<U>, E>
+    where
+        F: FnOnce(T) -> Result<U, E>,
+    {
+        self.try_map(f)
+    }
+
+    fn try_map_required<U, F, N>(self, f: F, on_none: N) -> Result<Option<U>, E>
+    where
+        F: FnOnce(T) -> Option<U>,
+        N: FnOnce() -> E,
+    {
+        self.try_map(|x| f(x).ok_or_else(on_none))
+    }
+
+    fn try_map_flat<U, F>(self, f: F) -> Result<Option<U>, E>
+    where
+        F: FnOnce(T) -> Result<Result<U, E>, E>,
+    {
+        self.try_map(|x| f(x).and_then(|inner| inner))
+    }
+
+    fn try_map_iter<U, F>(self, f: F) -> Result<core::option::IntoIter<U>, E>
+    where
+        F: FnOnce(T) -> Result<U, E>,
+    {
+        self.try_map(f).map(Option::into_iter)
+    }
+
+    fn try_and_then_opt<U, F>(self, f: F) -> Result<Option<U>, E>
+    where
+        F: FnOnce(T) -> Option<U>,
+    {
+        Ok(self.extract().and_then(f))
+    }
+
+    fn try_map_flatten_inner<V, U, F>(self, f: F) -> Result<Option<U>, E>
+    where
+        T: ExtractOption<V>,
+        F: FnOnce(V) -> Result<U, E>,
+    {
+        self.extract().and_then(ExtractOption::extract).try_map(f)
+    }
+
+    fn try_map_fn<U>(self, f: fn(T) -> Result<U, E>) -> Result<Option<U>, E> {
+        self.try_map(f)
+    }
+
+    fn try_contains<F>(self, f: F) -> Result<bool, E>
+    where
+        F: FnOnce(&T) -> Result<bool, E>,
+    {
+        self.extract().as_ref().map_or(Ok(false), f)
+    }
+
+    fn try_map_roundtrip<U, F, G>(self, to: F, from: G) -> Result<Option<T>, RoundtripError<E>>
+    where
+        F: FnOnce(&T) -> Result<U, E>,
+        G: FnOnce(U) -> Result<T, E>,
+        T: PartialEq,
+    {
+        match self.extract() {
+            Some(x) => {
+                let back = to(&x).and_then(from).map_err(RoundtripError::Mapper)?;
+
+                if back == x {
+                    Ok(Some(x))
+                } else {
+                    Err(RoundtripError::Mismatch)
+                }
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn try_map_vec<U, F>(self, f: F) -> Result<Option<Vec<U>>, E>
+    where
+        T: IntoIterator,
+        F: FnMut(T::Item) -> Result<U, E>,
+    {
+        self.try_map(|values| values.into_iter().try_map(f).collect())
+    }
+
+    fn try_map_or_abort<U, F, N>(self, f: F, on_none: N) -> Result<U, E>
+    where
+        F: FnOnce(T) -> Result<U, E>,
+        N: FnOnce() -> E,
+    {
+        self.extract().ok_or_else(on_none).and_then(f)
+    }
+
+    fn try_map_into_result<U, R, F>(self, f: F) -> Result<Option<U>, E>
+    where
+        F: FnOnce(T) -> R,
+        R: Into<Result<U, E>>,
+    {
+        self.try_map(|x| f(x).into())
     }
 }
 
-/// Extend iterator with fallible map functionality.
-pub trait FallibleMapIteratorExt: Iterator {
-    /// Attempt to map a function over an iterator, returning a `Result` iterator.
+/// Extend `Option` with fallible methods taking the option by reference.
+///
+/// Unlike `FallibleMapExt`, these methods leave the option in place, so the
+/// mapped value and the error may borrow from the inner value, or the option
+/// can be filled in.
+///
+/// # Type Parameters
+///
+/// - `T`: The option's value type
+pub trait FallibleMapRefExt<T> {
+    /// Attempt to map a function over a borrowed optional value.
     ///
     /// # Parameters
     ///
-    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    /// - `f`: A function that takes a `&'a T` and returns a `Result<B, E>`, where `B` and `E` may borrow for `'a`.
     ///
     /// # Returns
     ///
-    /// An iterator where each item is a `Result<B, E>`.
-    fn try_map<B, F, E>(self, f: F) -> FallibleMapIterator<Self, F, B, E>
+    /// A `Result` containing an `Option<B>`, or an error `E`.
+    fn try_map_borrow<'a, B, F, E>(&'a self, f: F) -> Result<Option<B>, E>
     where
-        Self: Sized,
-        F: FnMut(Self::Item) -> Result<B, E>;
+        T: 'a,
+        F: FnOnce(&'a T) -> Result<B, E>;
+
+    /// Return a clone of the value, computing and inserting it first if the option is empty.
+    ///
+    /// If `f` fails, the option stays `None`.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that returns a `Result<T, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a clone of the (possibly just inserted) value, or the error `E`.
+    fn try_get_or_insert<F, E>(&mut self, f: F) -> Result<T, E>
+    where
+        T: Clone,
+        F: FnOnce() -> Result<T, E>;
 }
 
-/// Implementation of `FallibleMapIteratorExt` for all iterators.
-impl<I> FallibleMapIteratorExt for I
-where
-    I: Iterator,
-{
-    fn try_map<B, F, E>(self, f: F) -> FallibleMapIterator<Self, F, B, E>
+/// Implementation of `FallibleMapRefExt` for `Option`.
+impl<T> FallibleMapRefExt<T> for Option<T> {
+    fn try_map_borrow<'a, B, F, E>(&'a self, f: F) -> Result<Option<B>, E>
     where
-        Self: Sized,
-        F: FnMut(Self::Item) -> Result<B, E>,
+        T: 'a,
+        F: FnOnce(&'a T) -> Result<B, E>,
     {
-        FallibleMapIterator::new(self, f)
+        self.as_ref().try_map(f)
+    }
+
+    fn try_get_or_insert<F, E>(&mut self, f: F) -> Result<T, E>
+    where
+        T: Clone,
+        F: FnOnce() -> Result<T, E>,
+    {
+        match self {
+            Some(x) => Ok(x.clone()),
+            None => Ok(self.insert(f()?).clone()),
+        }
+    }
+}
+
+/// Extend `Option<&T>` with fallible methods over the dereferenced value.
+///
+/// `Option<&T>` already implements `FallibleMapExt` through `ExtractOption`,
+/// handing `&T` to the closure; this trait goes one step further for `T: Deref`,
+/// such as `Option<&String>` to `&str`.
+///
+/// # Type Parameters
+///
+/// - `'a`: The lifetime of the reference
+/// - `T`: The referenced type
+pub trait FallibleMapDerefExt<'a, T>
+where
+    T: core::ops::Deref + ?Sized + 'a,
+    T::Target: 'a,
+{
+    /// Attempt to map a function over the dereferenced optional value.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes a `&'a T::Target` and returns a `Result<U, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an `Option<U>`, or an error `E`.
+    fn try_map_deref<U, F, E>(self, f: F) -> Result<Option<U>, E>
+    where
+        F: FnOnce(&'a T::Target) -> Result<U, E>;
+}
+
+/// Implementation of `FallibleMapDerefExt` for `Option<&T>`.
+impl<'a, T> FallibleMapDerefExt<'a, T> for Option<&'a T>
+where
+    T: core::ops::Deref + ?Sized + 'a,
+    T::Target: 'a,
+{
+    fn try_map_deref<U, F, E>(self, f: F) -> Result<Option<U>, E>
+    where
+        F: FnOnce(&'a T::Target) -> Result<U, E>,
+    {
+        self.map(T::deref).try_map(f)
+    }
+}
+
+/// Extend `Vec<Option<T>>` with fallible methods over the present values.
+///
+/// # Type Parameters
+///
+/// - `T`: The options' value type
+pub trait FallibleMapOptionsExt<T> {
+    /// Attempt to map a function over every present value, keeping `None` slots in place.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes a value of type `T` and returns a `Result<U, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `Vec<Option<U>>` of the same length, or the first error `E`.
+    fn try_map_options<U, F, E>(self, f: F) -> Result<Vec<Option<U>>, E>
+    where
+        F: FnMut(T) -> Result<U, E>;
+}
+
+/// Implementation of `FallibleMapOptionsExt` for `Vec<Option<T>>`.
+impl<T> FallibleMapOptionsExt<T> for Vec<Option<T>> {
+    fn try_map_options<U, F, E>(self, mut f: F) -> Result<Vec<Option<U>>, E>
+    where
+        F: FnMut(T) -> Result<U, E>,
+    {
+        self.into_iter().map(|x| x.try_map(&mut f)).collect()
+    }
+}
+
+/// Extend `Cow<'a, [T]>` with fallible mapping that avoids cloning the elements.
+///
+/// # Type Parameters
+///
+/// - `'a`: The lifetime of the borrowed slice
+/// - `T`: The slice's element type
+pub trait FallibleMapCowSliceExt<'a, T>
+where
+    T: Clone + 'a,
+{
+    /// Attempt to map a function over every element of the slice.
+    ///
+    /// A borrowed slice hands each element to `f` as `Cow::Borrowed`, while an
+    /// owned one is consumed and hands each element over as `Cow::Owned`, so no
+    /// element is ever cloned by this method.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes a `Cow<'a, T>` and returns a `Result<U, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `Vec<U>`, or the first error `E`.
+    fn try_map_cow_slice<U, F, E>(self, f: F) -> Result<Vec<U>, E>
+    where
+        F: FnMut(Cow<'a, T>) -> Result<U, E>;
+}
+
+/// Implementation of `FallibleMapCowSliceExt` for `Cow<'a, [T]>`.
+impl<'a, T> FallibleMapCowSliceExt<'a, T> for Cow<'a, [T]>
+where
+    T: Clone + 'a,
+{
+    fn try_map_cow_slice<U, F, E>(self, f: F) -> Result<Vec<U>, E>
+    where
+        F: FnMut(Cow<'a, T>) -> Result<U, E>,
+    {
+        match self {
+            Cow::Borrowed(slice) => slice.iter().map(Cow::Borrowed).try_map(f).collect(),
+            Cow::Owned(vec) => vec.into_iter().map(Cow::Owned).try_map(f).collect(),
+        }
+    }
+}
+
+/// Extend `(K, Option<V>)` pairs, such as map entries, with fallible mapping of the optional value.
+///
+/// # Type Parameters
+///
+/// - `K`: The key type, kept unchanged
+/// - `V`: The optional value's type
+pub trait FallibleMapPairExt<K, V> {
+    /// Attempt to map a function over the optional value, keeping the key.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes a value of type `V` and returns a `Result<U, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the key paired with an `Option<U>`, or an error `E`.
+    fn try_map_value<U, F, E>(self, f: F) -> Result<(K, Option<U>), E>
+    where
+        F: FnOnce(V) -> Result<U, E>;
+}
+
+/// Implementation of `FallibleMapPairExt` for `(K, Option<V>)`.
+impl<K, V> FallibleMapPairExt<K, V> for (K, Option<V>) {
+    fn try_map_value<U, F, E>(self, f: F) -> Result<(K, Option<U>), E>
+    where
+        F: FnOnce(V) -> Result<U, E>,
+    {
+        let (key, value) = self;
+
+        Ok((key, value.try_map(f)?))
+    }
+}
+
+/// Extend `[f32]` with fallible numeric mapping in fixed-size chunks.
+pub trait FallibleMapF32Ext {
+    /// Attempt to map a function over every element, processing the slice in fixed-size chunks.
+    ///
+    /// Each chunk is mapped into a fixed-size buffer before being appended to
+    /// the output, a shape the optimizer can vectorize when `f` is simple.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes an `f32` and returns a `Result<f32, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `Vec<f32>`, or the first error `E`.
+    fn try_map_f32_chunks<F, E>(&self, f: F) -> Result<Vec<f32>, E>
+    where
+        F: FnMut(f32) -> Result<f32, E>;
+}
+
+/// Implementation of `FallibleMapF32Ext` for `[f32]`.
+impl FallibleMapF32Ext for [f32] {
+    fn try_map_f32_chunks<F, E>(&self, mut f: F) -> Result<Vec<f32>, E>
+    where
+        F: FnMut(f32) -> Result<f32, E>,
+    {
+        const CHUNK: usize = 8;
+
+        let mut values = Vec::with_capacity(self.len());
+        let chunks = self.chunks_exact(CHUNK);
+        let remainder = chunks.remainder();
+
+        for chunk in chunks {
+            let mut buffer = [0.0; CHUNK];
+
+            for (out, &x) in buffer.iter_mut().zip(chunk) {
+                *out = f(x)?;
+            }
+
+            values.extend_from_slice(&buffer);
+        }
+
+        for &x in remainder {
+            values.push(f(x)?);
+        }
+
+        Ok(values)
+    }
+}
+
+/// A fallible map iterator that maps a function returning a `Result` over the elements of the underlying iterator.
+pub struct FallibleMapIterator<I, F, B, E> {
+    iter: I,
+    f: F,
+    _marker: core::marker::PhantomData<(B, E)>,
+}
+
+impl<I, F, B, E> FallibleMapIterator<I, F, B, E> {
+    pub fn new(iter: I, f: F) -> Self {
+        FallibleMapIterator {
+            iter,
+            f,
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<I, F, B, E> FallibleMapIterator<I, F, B, E>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> Result<B, E>,
+{
+    /// Expose the adapter as a plain iterator of `Result`s.
+    ///
+    /// This is a zero-cost identity conversion, named to signal intent when
+    /// handing the iterator to consumers such as `itertools::process_results`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator where each item is a `Result<B, E>`.
+    pub fn results(self) -> impl Iterator<Item = Result<B, E>> {
+        self
+    }
+
+    /// Collect the mapped values into a `Vec`, stopping at the first error.
+    ///
+    /// Equivalent to `collect::<Result<Vec<B>, E>>()`, without the type annotation.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `Vec<B>`, or the first error `E`.
+    pub fn collect_result(self) -> Result<Vec<B>, E> {
+        self.collect()
+    }
+
+    /// Expose the adapter as a `Stream` of `Result`s.
+    ///
+    /// Each item is computed synchronously when the stream is polled, and the
+    /// stream is always ready, so this is only suitable for mapping functions
+    /// that do not block.
+    ///
+    /// # Returns
+    ///
+    /// A stream where each item is a `Result<B, E>`.
+    #[cfg(feature = "async")]
+    pub fn into_stream(self) -> impl futures::Stream<Item = Result<B, E>> {
+        futures::stream::iter(self)
+    }
+
+    /// Yield the successfully mapped values, stopping at the first error.
+    ///
+    /// The terminating error, if any, is captured and can be retrieved with
+    /// `TakeWhileOk::into_err` once iteration is over.
+    ///
+    /// # Returns
+    ///
+    /// An iterator where each item is a `B`.
+    pub fn take_while_ok(self) -> TakeWhileOk<Self, E> {
+        TakeWhileOk::new(self)
+    }
+
+    /// Map an infallible function over the `Ok` values, passing errors through unchanged.
+    ///
+    /// # Parameters
+    ///
+    /// - `g`: A function that takes a value of type `B` and returns a `B2`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator where each item is a `Result<B2, E>`.
+    pub fn map_ok<B2, G>(self, g: G) -> MapOk<Self, G>
+    where
+        G: FnMut(B) -> B2,
+    {
+        MapOk::new(self, g)
+    }
+
+    /// Chain a second fallible function over the `Ok` values, passing errors through unchanged.
+    ///
+    /// # Parameters
+    ///
+    /// - `g`: A function that takes a value of type `B` and returns a `Result<B2, E>`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator where each item is a `Result<B2, E>`.
+    pub fn and_then_try<B2, G>(self, g: G) -> AndThenTry<Self, G>
+    where
+        G: FnMut(B) -> Result<B2, E>,
+    {
+        AndThenTry::new(self, g)
+    }
+
+    /// Place a separator computed by a fallible function between consecutive items.
+    ///
+    /// If computing a separator fails, its error is yielded and the iteration ends.
+    ///
+    /// # Parameters
+    ///
+    /// - `sep_fn`: A function that returns a `Result<B, E>` for each separator.
+    ///
+    /// # Returns
+    ///
+    /// An iterator where each item is a `Result<B, E>`.
+    pub fn try_intersperse_with<G>(self, sep_fn: G) -> TryIntersperseWith<Self, G>
+    where
+        G: FnMut() -> Result<B, E>,
+    {
+        TryIntersperseWith::new(self, sep_fn)
+    }
+
+    /// Alternate between the items of this adapter and those of another `Result` iterator.
+    ///
+    /// Once either side is exhausted, the remaining items of the other one are
+    /// yielded in order. Errors pass through like any other item.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: An iterator where each item is a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator where each item is a `Result<B, E>`.
+    pub fn try_interleave<J>(self, other: J) -> TryInterleave<Self, J>
+    where
+        J: Iterator<Item = Result<B, E>>,
+    {
+        TryInterleave::new(self, other)
+    }
+
+    /// Make the adapter rewindable, keeping a copy of the source iterator.
+    ///
+    /// # Returns
+    ///
+    /// An iterator where each item is a `Result<B, E>`, which can be reset to its beginning.
+    pub fn rewindable(self) -> Rewindable<I, F, B, E>
+    where
+        I: Clone,
+    {
+        Rewindable::new(self)
+    }
+
+    /// Split the adapter into the successfully mapped values and a handle to the terminating error.
+    ///
+    /// The values stop at the first error, which is stored in a shared cell and
+    /// can be retrieved by calling the handle once the values have been consumed.
+    /// Calling the handle earlier returns `None`.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing an iterator where each item is a `B`, and a function
+    /// returning the terminating error, if any.
+    pub fn split_at_error(self) -> (impl Iterator<Item = B>, impl FnOnce() -> Option<E>) {
+        let slot = Rc::new(RefCell::new(None));
+        let error = Rc::clone(&slot);
+        let values = self
+            .map_while(move |result| match result {
+                Ok(x) => Some(x),
+                Err(e) => {
+                    *slot.borrow_mut() = Some(e);
+                    None
+                }
+            })
+            .fuse();
+
+        (values, move || error.borrow_mut().take())
+    }
+}
+
+/// Implement `Iterator` for `FallibleMap` where the iterator item is a `Result`.
+impl<I, F, B, E> Iterator for FallibleMapIterator<I, F, B, E>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> Result<B, E>,
+{
+    type Item = Result<B, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(&mut self.f)
+    }
+}
+
+/// A fallible map iterator that can be reset to its beginning by re-cloning the original source.
+pub struct Rewindable<I, F, B, E> {
+    original: I,
+    inner: FallibleMapIterator<I, F, B, E>,
+}
+
+impl<I, F, B, E> Rewindable<I, F, B, E>
+where
+    I: Clone,
+{
+    pub fn new(inner: FallibleMapIterator<I, F, B, E>) -> Self {
+        Rewindable {
+            original: inner.iter.clone(),
+            inner,
+        }
+    }
+
+    /// Restore iteration to the beginning of the original source.
+    pub fn reset(&mut self) {
+        self.inner.iter = self.original.clone();
+    }
+}
+
+/// Implement `Iterator` for `Rewindable` where the iterator item is a `Result`.
+impl<I, F, B, E> Iterator for Rewindable<I, F, B, E>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> Result<B, E>,
+{
+    type Item = Result<B, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+/// An iterator that maps an infallible function over the `Ok` values of a `Result` iterator.
+pub struct MapOk<I, G> {
+    iter: I,
+    g: G,
+}
+
+impl<I, G> MapOk<I, G> {
+    pub fn new(iter: I, g: G) -> Self {
+        MapOk { iter, g }
+    }
+}
+
+/// Implement `Iterator` for `MapOk` where the iterator item is a `Result`.
+impl<I, G, B, B2, E> Iterator for MapOk<I, G>
+where
+    I: Iterator<Item = Result<B, E>>,
+    G: FnMut(B) -> B2,
+{
+    type Item = Result<B2, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|x| x.map(&mut self.g))
+    }
+}
+
+/// An iterator that chains a fallible function over the `Ok` values of a `Result` iterator.
+pub struct AndThenTry<I, G> {
+    iter: I,
+    g: G,
+}
+
+impl<I, G> AndThenTry<I, G> {
+    pub fn new(iter: I, g: G) -> Self {
+        AndThenTry { iter, g }
+    }
+}
+
+/// Implement `Iterator` for `AndThenTry` where the iterator item is a `Result`.
+impl<I, G, B, B2, E> Iterator for AndThenTry<I, G>
+where
+    I: Iterator<Item = Result<B, E>>,
+    G: FnMut(B) -> Result<B2, E>,
+{
+    type Item = Result<B2, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|x| x.and_then(&mut self.g))
+    }
+}
+
+/// An iterator that places a separator computed by a fallible function between the items of a `Result` iterator.
+pub struct TryIntersperseWith<I, G>
+where
+    I: Iterator,
+{
+    iter: core::iter::Peekable<I>,
+    sep_fn: G,
+    needs_sep: bool,
+    done: bool,
+}
+
+impl<I, G> TryIntersperseWith<I, G>
+where
+    I: Iterator,
+{
+    pub fn new(iter: I, sep_fn: G) -> Self {
+        TryIntersperseWith {
+            iter: iter.peekable(),
+            sep_fn,
+            needs_sep: false,
+            done: false,
+        }
+    }
+}
+
+/// Implement `Iterator` for `TryIntersperseWith` where the iterator item is a `Result`.
+impl<I, G, B, E> Iterator for TryIntersperseWith<I, G>
+where
+    I: Iterator<Item = Result<B, E>>,
+    G: FnMut() -> Result<B, E>,
+{
+    type Item = Result<B, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        if self.needs_sep && self.iter.peek().is_some() {
+            self.needs_sep = false;
+
+            let sep = (self.sep_fn)();
+
+            self.done = sep.is_err();
+
+            return Some(sep);
+        }
+
+        let item = self.iter.next()?;
+
+        self.needs_sep = true;
+
+        Some(item)
+    }
+}
+
+/// An iterator that alternates between the items of two `Result` iterators.
+pub struct TryInterleave<I, J> {
+    a: core::iter::Fuse<I>,
+    b: core::iter::Fuse<J>,
+    flag: bool,
+}
+
+impl<I, J> TryInterleave<I, J>
+where
+    I: Iterator,
+    J: Iterator,
+{
+    pub fn new(a: I, b: J) -> Self {
+        TryInterleave {
+            a: a.fuse(),
+            b: b.fuse(),
+            flag: false,
+        }
+    }
+}
+
+/// Implement `Iterator` for `TryInterleave` where the iterator item is a `Result`.
+impl<I, J, B, E> Iterator for TryInterleave<I, J>
+where
+    I: Iterator<Item = Result<B, E>>,
+    J: Iterator<Item = Result<B, E>>,
+{
+    type Item = Result<B, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.flag = !self.flag;
+
+        if self.flag {
+            self.a.next().or_else(|| self.b.next())
+        } else {
+            self.b.next().or_else(|| self.a.next())
+        }
+    }
+}
+
+/// An iterator over the `Ok` values of a `Result` iterator, which stops at the first error and keeps it.
+pub struct TakeWhileOk<I, E> {
+    iter: I,
+    error: Option<E>,
+    done: bool,
+}
+
+impl<I, E> TakeWhileOk<I, E> {
+    pub fn new(iter: I) -> Self {
+        TakeWhileOk {
+            iter,
+            error: None,
+            done: false,
+        }
+    }
+
+    /// Consume the adapter, returning the error that stopped the iteration, if any.
+    pub fn into_err(self) -> Option<E> {
+        self.error
+    }
+}
+
+/// Implement `Iterator` for `TakeWhileOk` where the iterator item is the `Ok` value.
+impl<I, B, E> Iterator for TakeWhileOk<I, E>
+where
+    I: Iterator<Item = Result<B, E>>,
+{
+    type Item = B;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match self.iter.next() {
+            Some(Ok(x)) => Some(x),
+            Some(Err(e)) => {
+                self.error = Some(e);
+                self.done = true;
+                None
+            }
+            None => {
+                self.done = true;
+                None
+            }
+        }
+    }
+}
+
+/// A fallible map iterator that also measures how long each call to the mapping function takes.
+#[cfg(feature = "std")]
+pub struct FallibleMapTimedIterator<I, F, B, E> {
+    iter: I,
+    f: F,
+    _marker: core::marker::PhantomData<(B, E)>,
+}
+
+#[cfg(feature = "std")]
+impl<I, F, B, E> FallibleMapTimedIterator<I, F, B, E> {
+    pub fn new(iter: I, f: F) -> Self {
+        FallibleMapTimedIterator {
+            iter,
+            f,
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+/// Implement `Iterator` for `FallibleMapTimedIterator` where the iterator item is a timed `Result`.
+#[cfg(feature = "std")]
+impl<I, F, B, E> Iterator for FallibleMapTimedIterator<I, F, B, E>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> Result<B, E>,
+{
+    type Item = Result<(B, std::time::Duration), E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let start = std::time::Instant::now();
+
+        Some((self.f)(item).map(|x| (x, start.elapsed())))
+    }
+}
+
+/// A fallible map iterator that waits for a minimum interval between calls to the mapping function.
+#[cfg(feature = "std")]
+pub struct FallibleMapThrottledIterator<I, F, B, E> {
+    iter: I,
+    f: F,
+    min_interval: std::time::Duration,
+    last_call: Option<std::time::Instant>,
+    _marker: core::marker::PhantomData<(B, E)>,
+}
+
+#[cfg(feature = "std")]
+impl<I, F, B, E> FallibleMapThrottledIterator<I, F, B, E> {
+    pub fn new(iter: I, min_interval: std::time::Duration, f: F) -> Self {
+        FallibleMapThrottledIterator {
+            iter,
+            f,
+            min_interval,
+            last_call: None,
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+/// Implement `Iterator` for `FallibleMapThrottledIterator` where the iterator item is a `Result`.
+#[cfg(feature = "std")]
+impl<I, F, B, E> Iterator for FallibleMapThrottledIterator<I, F, B, E>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> Result<B, E>,
+{
+    type Item = Result<B, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+
+        if let Some(last_call) = self.last_call {
+            let elapsed = last_call.elapsed();
+
+            if elapsed < self.min_interval {
+                std::thread::sleep(self.min_interval - elapsed);
+            }
+        }
+
+        self.last_call = Some(std::time::Instant::now());
+
+        Some((self.f)(item))
+    }
+}
+
+/// A fallible map iterator that retries each failing item, sleeping exponentially longer between attempts.
+#[cfg(feature = "std")]
+pub struct FallibleMapBackoffIterator<I, F, B, E> {
+    iter: I,
+    f: F,
+    base: std::time::Duration,
+    max_retries: usize,
+    _marker: core::marker::PhantomData<(B, E)>,
+}
+
+#[cfg(feature = "std")]
+impl<I, F, B, E> FallibleMapBackoffIterator<I, F, B, E> {
+    pub fn new(iter: I, base: std::time::Duration, max_retries: usize, f: F) -> Self {
+        FallibleMapBackoffIterator {
+            iter,
+            f,
+            base,
+            max_retries,
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+/// Implement `Iterator` for `FallibleMapBackoffIterator` where the iterator item is a `Result`.
+#[cfg(feature = "std")]
+impl<I, F, B, E> Iterator for FallibleMapBackoffIterator<I, F, B, E>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> Result<B, E>,
+{
+    type Item = Result<B, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let mut result = (self.f)(&item);
+        let mut delay = self.base;
+
+        for _ in 0..self.max_retries {
+            if result.is_ok() {
+                break;
+            }
+
+            std::thread::sleep(delay);
+
+            delay = delay.saturating_mul(2);
+            result = (self.f)(&item);
+        }
+
+        Some(result)
+    }
+}
+
+/// A fallible map iterator that skips items already recorded in a set, recording each successfully mapped one.
+#[cfg(feature = "std")]
+pub struct FallibleMapDedupPersistIterator<'a, I, F, B, E>
+where
+    I: Iterator,
+{
+    iter: I,
+    f: F,
+    seen: &'a mut std::collections::HashSet<I::Item>,
+    _marker: core::marker::PhantomData<(B, E)>,
+}
+
+#[cfg(feature = "std")]
+impl<'a, I, F, B, E> FallibleMapDedupPersistIterator<'a, I, F, B, E>
+where
+    I: Iterator,
+{
+    pub fn new(iter: I, seen: &'a mut std::collections::HashSet<I::Item>, f: F) -> Self {
+        FallibleMapDedupPersistIterator {
+            iter,
+            f,
+            seen,
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+/// Implement `Iterator` for `FallibleMapDedupPersistIterator` where the iterator item is a `Result`.
+#[cfg(feature = "std")]
+impl<'a, I, F, B, E> Iterator for FallibleMapDedupPersistIterator<'a, I, F, B, E>
+where
+    I: Iterator,
+    I::Item: Eq + core::hash::Hash + Clone,
+    F: FnMut(I::Item) -> Result<B, E>,
+{
+    type Item = Result<B, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let item = self.iter.next()?;
+
+            if self.seen.contains(&item) {
+                continue;
+            }
+
+            let result = (self.f)(item.clone());
+
+            if result.is_ok() {
+                self.seen.insert(item);
+            }
+
+            return Some(result);
+        }
+    }
+}
+
+/// A fallible map iterator that stops yielding once a deadline has passed.
+#[cfg(feature = "std")]
+pub struct FallibleMapDeadlineIterator<I, F, B, E> {
+    iter: I,
+    f: F,
+    deadline: std::time::Instant,
+    _marker: core::marker::PhantomData<(B, E)>,
+}
+
+#[cfg(feature = "std")]
+impl<I, F, B, E> FallibleMapDeadlineIterator<I, F, B, E> {
+    pub fn new(iter: I, deadline: std::time::Instant, f: F) -> Self {
+        FallibleMapDeadlineIterator {
+            iter,
+            f,
+            deadline,
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+/// Implement `Iterator` for `FallibleMapDeadlineIterator` where the iterator item is a `Result`.
+#[cfg(feature = "std")]
+impl<I, F, B, E> Iterator for FallibleMapDeadlineIterator<I, F, B, E>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> Result<B, E>,
+{
+    type Item = Result<B, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if std::time::Instant::now() >= self.deadline {
+            return None;
+        }
+
+        self.iter.next().map(&mut self.f)
+    }
+}
+
+/// A fallible map iterator that runs each call to the mapping function on a worker thread, giving up after a timeout.
+#[cfg(feature = "std")]
+pub struct FallibleMapTimeoutIterator<I, F, B, E> {
+    iter: I,
+    f: std::sync::Arc<F>,
+    per_item: std::time::Duration,
+    _marker: core::marker::PhantomData<(B, E)>,
+}
+
+#[cfg(feature = "std")]
+impl<I, F, B, E> FallibleMapTimeoutIterator<I, F, B, E> {
+    pub fn new(iter: I, per_item: std::time::Duration, f: F) -> Self {
+        FallibleMapTimeoutIterator {
+            iter,
+            f: std::sync::Arc::new(f),
+            per_item,
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+/// Implement `Iterator` for `FallibleMapTimeoutIterator` where the iterator item is a `Result`.
+#[cfg(feature = "std")]
+impl<I, F, B, E> Iterator for FallibleMapTimeoutIterator<I, F, B, E>
+where
+    I: Iterator,
+    I::Item: Send + 'static,
+    F: Fn(I::Item) -> Result<B, E> + Send + Sync + 'static,
+    B: Send + 'static,
+    E: Send + 'static,
+{
+    type Item = Result<B, TimeoutError<E>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        use std::sync::mpsc::RecvTimeoutError;
+
+        let item = self.iter.next()?;
+        let f = std::sync::Arc::clone(&self.f);
+        let (tx, rx) = std::sync::mpsc::channel();
+
+        let handle = std::thread::spawn(move || {
+            let _ = tx.send(f(item));
+        });
+
+        Some(match rx.recv_timeout(self.per_item) {
+            Ok(result) => result.map_err(TimeoutError::Mapper),
+            Err(RecvTimeoutError::Timeout) => Err(TimeoutError::Timeout),
+            Err(RecvTimeoutError::Disconnected) => match handle.join() {
+                Err(e) => std::panic::resume_unwind(e),
+                Ok(()) => unreachable!("the worker thread exited without sending a result"),
+            },
+        })
+    }
+}
+
+/// A fallible map iterator that yields, for each item, a lazy inner iterator computed by a function returning a `Result`.
+pub struct FallibleMapNestedIterator<I, F, J, E> {
+    iter: I,
+    f: F,
+    _marker: core::marker::PhantomData<(J, E)>,
+}
+
+impl<I, F, J, E> FallibleMapNestedIterator<I, F, J, E> {
+    pub fn new(iter: I, f: F) -> Self {
+        FallibleMapNestedIterator {
+            iter,
+            f,
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+/// Implement `Iterator` for `FallibleMapNestedIterator` where the iterator item is a `Result` of an inner iterator.
+impl<I, F, J, E> Iterator for FallibleMapNestedIterator<I, F, J, E>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> Result<J, E>,
+    J: IntoIterator,
+{
+    type Item = Result<J::IntoIter, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|x| (self.f)(x).map(IntoIterator::into_iter))
+    }
+}
+
+/// A fallible map iterator that feeds every successfully mapped value into a running `Hasher`.
+pub struct FallibleMapHashedIterator<I, F, H, B, E> {
+    iter: I,
+    f: F,
+    hasher: H,
+    _marker: core::marker::PhantomData<(B, E)>,
+}
+
+impl<I, F, H, B, E> FallibleMapHashedIterator<I, F, H, B, E> {
+    pub fn new(iter: I, hasher: H, f: F) -> Self {
+        FallibleMapHashedIterator {
+            iter,
+            f,
+            hasher,
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Return the hash of the values mapped so far.
+    pub fn finish_hash(&self) -> u64
+    where
+        H: core::hash::Hasher,
+    {
+        self.hasher.finish()
+    }
+}
+
+/// Implement `Iterator` for `FallibleMapHashedIterator` where the iterator item is a `Result`.
+impl<I, F, H, B, E> Iterator for FallibleMapHashedIterator<I, F, H, B, E>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> Result<B, E>,
+    H: core::hash::Hasher,
+    B: core::hash::Hash,
+{
+    type Item = Result<B, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = (self.f)(self.iter.next()?);
+
+        if let Ok(x) = &result {
+            x.hash(&mut self.hasher);
+        }
+
+        Some(result)
+    }
+}
+
+/// A fallible map iterator that substitutes a default value for every error.
+pub struct FallibleMapOrValueIterator<I, F, B, E> {
+    iter: I,
+    f: F,
+    default: B,
+    _marker: core::marker::PhantomData<E>,
+}
+
+impl<I, F, B, E> FallibleMapOrValueIterator<I, F, B, E> {
+    pub fn new(iter: I, default: B, f: F) -> Self {
+        FallibleMapOrValueIterator {
+            iter,
+            f,
+            default,
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+/// Implement `Iterator` for `FallibleMapOrValueIterator` where the iterator item is the mapped or default value.
+impl<I, F, B, E> Iterator for FallibleMapOrValueIterator<I, F, B, E>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> Result<B, E>,
+    B: Clone,
+{
+    type Item = B;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+
+        Some((self.f)(item).unwrap_or_else(|_| self.default.clone()))
+    }
+}
+
+/// A fallible map iterator that hands each error to a recovery function, ending once recovery fails.
+pub struct FallibleMapOrElseIterator<I, F, R, B, E> {
+    iter: I,
+    f: F,
+    recover: R,
+    done: bool,
+    _marker: core::marker::PhantomData<(B, E)>,
+}
+
+impl<I, F, R, B, E> FallibleMapOrElseIterator<I, F, R, B, E> {
+    pub fn new(iter: I, f: F, recover: R) -> Self {
+        FallibleMapOrElseIterator {
+            iter,
+            f,
+            recover,
+            done: false,
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+/// Implement `Iterator` for `FallibleMapOrElseIterator` where the iterator item is a mapped or recovered `Result`.
+impl<I, F, R, B, E> Iterator for FallibleMapOrElseIterator<I, F, R, B, E>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> Result<B, E>,
+    R: FnMut(E) -> Result<B, E>,
+{
+    type Item = Result<B, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let item = self.iter.next()?;
+        let result = (self.f)(item).or_else(&mut self.recover);
+
+        self.done = result.is_err();
+
+        Some(result)
+    }
+}
+
+/// A fallible map iterator that logs each error at the `error` level before yielding it.
+#[cfg(feature = "log")]
+pub struct FallibleMapLoggedIterator<I, F, B, E> {
+    iter: I,
+    f: F,
+    _marker: core::marker::PhantomData<(B, E)>,
+}
+
+#[cfg(feature = "log")]
+impl<I, F, B, E> FallibleMapLoggedIterator<I, F, B, E> {
+    pub fn new(iter: I, f: F) -> Self {
+        FallibleMapLoggedIterator {
+            iter,
+            f,
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+/// Implement `Iterator` for `FallibleMapLoggedIterator` where the iterator item is a `Result`.
+#[cfg(feature = "log")]
+impl<I, F, B, E> Iterator for FallibleMapLoggedIterator<I, F, B, E>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> Result<B, E>,
+    E: core::fmt::Display,
+{
+    type Item = Result<B, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = (self.f)(self.iter.next()?);
+
+        if let Err(e) = &result {
+            log::error!("{}", e);
+        }
+
+        Some(result)
+    }
+}
+
+/// A fallible map iterator over overlapping windows of consecutive items.
+pub struct FallibleWindowsIterator<I, F, B, E>
+where
+    I: Iterator,
+{
+    iter: I,
+    f: F,
+    size: usize,
+    window: alloc::collections::VecDeque<I::Item>,
+    start: usize,
+    done: bool,
+    _marker: core::marker::PhantomData<(B, E)>,
+}
+
+impl<I, F, B, E> FallibleWindowsIterator<I, F, B, E>
+where
+    I: Iterator,
+    F: FnMut(&[I::Item]) -> Result<B, E>,
+{
+    pub fn new(iter: I, size: usize, f: F) -> Self {
+        assert!(size > 0, "window size must be non-zero");
+
+        FallibleWindowsIterator {
+            iter,
+            f,
+            size,
+            window: alloc::collections::VecDeque::with_capacity(size),
+            start: 0,
+            done: false,
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Pair each mapped window with the range of input positions it was computed from.
+    ///
+    /// # Returns
+    ///
+    /// An iterator where each item is a `Result<(Range<usize>, B), E>`.
+    pub fn with_ranges(self) -> FallibleWindowsRangedIterator<I, F, B, E> {
+        FallibleWindowsRangedIterator { inner: self }
+    }
+
+    fn next_ranged(&mut self) -> Option<Result<(core::ops::Range<usize>, B), E>> {
+        if self.done {
+            return None;
+        }
+
+        if self.window.len() == self.size {
+            self.window.pop_front();
+            self.start += 1;
+        }
+
+        while self.window.len() < self.size {
+            match self.iter.next() {
+                Some(item) => self.window.push_back(item),
+                None => {
+                    self.done = true;
+
+                    return None;
+                }
+            }
+        }
+
+        let range = self.start..self.start + self.size;
+
+        Some((self.f)(self.window.make_contiguous()).map(|x| (range, x)))
+    }
+}
+
+/// Implement `Iterator` for `FallibleWindowsIterator` where the iterator item is a `Result`.
+impl<I, F, B, E> Iterator for FallibleWindowsIterator<I, F, B, E>
+where
+    I: Iterator,
+    F: FnMut(&[I::Item]) -> Result<B, E>,
+{
+    type Item = Result<B, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_ranged().map(|result| result.map(|(_, x)| x))
+    }
+}
+
+/// A fallible map iterator over overlapping windows, tagging each value with its input range.
+pub struct FallibleWindowsRangedIterator<I, F, B, E>
+where
+    I: Iterator,
+{
+    inner: FallibleWindowsIterator<I, F, B, E>,
+}
+
+/// Implement `Iterator` for `FallibleWindowsRangedIterator` where the iterator item is a `Result` of the range and value pair.
+impl<I, F, B, E> Iterator for FallibleWindowsRangedIterator<I, F, B, E>
+where
+    I: Iterator,
+    F: FnMut(&[I::Item]) -> Result<B, E>,
+{
+    type Item = Result<(core::ops::Range<usize>, B), E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next_ranged()
+    }
+}
+
+/// A fallible map iterator that wraps each call to the mapping function in a `tracing` span.
+#[cfg(feature = "tracing")]
+pub struct FallibleMapTracedIterator<I, F, B, E> {
+    iter: I,
+    f: F,
+    span_name: &'static str,
+    index: usize,
+    _marker: core::marker::PhantomData<(B, E)>,
+}
+
+#[cfg(feature = "tracing")]
+impl<I, F, B, E> FallibleMapTracedIterator<I, F, B, E> {
+    pub fn new(iter: I, span_name: &'static str, f: F) -> Self {
+        FallibleMapTracedIterator {
+            iter,
+            f,
+            span_name,
+            index: 0,
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+/// Implement `Iterator` for `FallibleMapTracedIterator` where the iterator item is a `Result`.
+#[cfg(feature = "tracing")]
+impl<I, F, B, E> Iterator for FallibleMapTracedIterator<I, F, B, E>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> Result<B, E>,
+    E: core::fmt::Display,
+{
+    type Item = Result<B, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let span = tracing::info_span!("try_map", name = self.span_name, index = self.index);
+        let _entered = span.enter();
+
+        self.index += 1;
+
+        let result = (self.f)(item);
+
+        match &result {
+            Ok(_) => tracing::debug!("item mapped"),
+            Err(e) => tracing::error!(error = %e, "item failed to map"),
+        }
+
+        Some(result)
+    }
+}
+
+/// A fallible map iterator that groups consecutive items into windows, reporting every error of each window.
+pub struct FallibleMapErrorWindowsIterator<I, F, B, E> {
+    iter: I,
+    f: F,
+    window: usize,
+    _marker: core::marker::PhantomData<(B, E)>,
+}
+
+impl<I, F, B, E> FallibleMapErrorWindowsIterator<I, F, B, E> {
+    pub fn new(iter: I, window: usize, f: F) -> Self {
+        assert!(window > 0, "window size must be non-zero");
+
+        FallibleMapErrorWindowsIterator {
+            iter,
+            f,
+            window,
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+/// Implement `Iterator` for `FallibleMapErrorWindowsIterator` where the iterator item is a `Result` of a window's values, or of its values and errors.
+impl<I, F, B, E> Iterator for FallibleMapErrorWindowsIterator<I, F, B, E>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> Result<B, E>,
+{
+    type Item = Result<Vec<B>, (Vec<B>, Vec<E>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut values = Vec::new();
+        let mut errors = Vec::new();
+
+        for item in self.iter.by_ref().take(self.window) {
+            match (self.f)(item) {
+                Ok(x) => values.push(x),
+                Err(e) => errors.push(e),
+            }
+        }
+
+        if !errors.is_empty() {
+            Some(Err((values, errors)))
+        } else if !values.is_empty() {
+            Some(Ok(values))
+        } else {
+            None
+        }
+    }
+}
+
+/// The signal returned by the mapping function of `try_map_until`, controlling the iteration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Step<B> {
+    /// Yield the value and continue.
+    Yield(B),
+    /// End the iteration without yielding a value.
+    Stop,
+    /// Yield the value and end the iteration.
+    YieldAndStop(B),
+}
+
+/// A fallible map iterator whose mapping function decides when the iteration ends.
+pub struct FallibleMapUntilIterator<I, F, B, E> {
+    iter: I,
+    f: F,
+    done: bool,
+    _marker: core::marker::PhantomData<(B, E)>,
+}
+
+impl<I, F, B, E> FallibleMapUntilIterator<I, F, B, E> {
+    pub fn new(iter: I, f: F) -> Self {
+        FallibleMapUntilIterator {
+            iter,
+            f,
+            done: false,
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+/// Implement `Iterator` for `FallibleMapUntilIterator` where the iterator item is a `Result`.
+impl<I, F, B, E> Iterator for FallibleMapUntilIterator<I, F, B, E>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> Result<Step<B>, E>,
+{
+    type Item = Result<B, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match (self.f)(self.iter.next()?) {
+            Ok(Step::Yield(x)) => Some(Ok(x)),
+            Ok(Step::Stop) => {
+                self.done = true;
+                None
+            }
+            Ok(Step::YieldAndStop(x)) => {
+                self.done = true;
+                Some(Ok(x))
+            }
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+/// A fallible map iterator that retries each failing item, pairing each value with the attempts it took.
+pub struct FallibleMapAttemptsIterator<I, F, B, E> {
+    iter: I,
+    f: F,
+    max: usize,
+    _marker: core::marker::PhantomData<(B, E)>,
+}
+
+impl<I, F, B, E> FallibleMapAttemptsIterator<I, F, B, E> {
+    pub fn new(iter: I, max: usize, f: F) -> Self {
+        FallibleMapAttemptsIterator {
+            iter,
+            f,
+            max: max.max(1),
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+/// Implement `Iterator` for `FallibleMapAttemptsIterator` where the iterator item is a `Result` of the value and attempt count pair.
+impl<I, F, B, E> Iterator for FallibleMapAttemptsIterator<I, F, B, E>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> Result<B, E>,
+{
+    type Item = Result<(B, usize), E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let mut attempts = 1;
+
+        loop {
+            match (self.f)(&item) {
+                Ok(x) => return Some(Ok((x, attempts))),
+                Err(e) if attempts == self.max => return Some(Err(e)),
+                Err(_) => attempts += 1,
+            }
+        }
+    }
+}
+
+/// The terminating error of a `FallibleMapDeferredIterator`, shared with the iterator and readable once it is consumed.
+#[derive(Debug)]
+pub struct DeferredError<E> {
+    slot: Rc<RefCell<Option<E>>>,
+}
+
+impl<E> DeferredError<E> {
+    /// Return whether the iterator has stopped at an error that was not taken yet.
+    pub fn is_err(&self) -> bool {
+        self.slot.borrow().is_some()
+    }
+
+    /// Take the error the iterator stopped at, if any.
+    pub fn take(&self) -> Option<E> {
+        self.slot.borrow_mut().take()
+    }
+}
+
+/// A fallible map iterator that yields the mapped values until the first error, which it stores in a `DeferredError`.
+pub struct FallibleMapDeferredIterator<I, F, B, E> {
+    iter: I,
+    f: F,
+    slot: Rc<RefCell<Option<E>>>,
+    done: bool,
+    _marker: core::marker::PhantomData<B>,
+}
+
+impl<I, F, B, E> FallibleMapDeferredIterator<I, F, B, E> {
+    pub fn new(iter: I, f: F) -> (Self, DeferredError<E>) {
+        let slot = Rc::new(RefCell::new(None));
+        let error = DeferredError {
+            slot: Rc::clone(&slot),
+        };
+        let iter = FallibleMapDeferredIterator {
+            iter,
+            f,
+            slot,
+            done: false,
+            _marker: core::marker::PhantomData,
+        };
+
+        (iter, error)
+    }
+}
+
+/// Implement `Iterator` for `FallibleMapDeferredIterator` where the iterator item is a mapped value.
+impl<I, F, B, E> Iterator for FallibleMapDeferredIterator<I, F, B, E>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> Result<B, E>,
+{
+    type Item = B;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match (self.f)(self.iter.next()?) {
+            Ok(x) => Some(x),
+            Err(e) => {
+                self.done = true;
+                *self.slot.borrow_mut() = Some(e);
+                None
+            }
+        }
+    }
+}
+
+/// A fallible iterator that expands each item into the elements of a `Vec` computed by a function returning a `Result`.
+pub struct FallibleExpandIterator<I, F, B, E> {
+    iter: I,
+    f: F,
+    current: Option<alloc::vec::IntoIter<B>>,
+    _marker: core::marker::PhantomData<E>,
+}
+
+impl<I, F, B, E> FallibleExpandIterator<I, F, B, E> {
+    pub fn new(iter: I, f: F) -> Self {
+        FallibleExpandIterator {
+            iter,
+            f,
+            current: None,
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+/// Implement `Iterator` for `FallibleExpandIterator` where the iterator item is a `Result`.
+impl<I, F, B, E> Iterator for FallibleExpandIterator<I, F, B, E>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> Result<Vec<B>, E>,
+{
+    type Item = Result<B, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(x) = self.current.as_mut().and_then(Iterator::next) {
+                return Some(Ok(x));
+            }
+
+            match (self.f)(self.iter.next()?) {
+                Ok(values) => self.current = Some(values.into_iter()),
+                Err(e) => {
+                    self.current = None;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
+
+/// An error raised while mapping an item, located by the item's position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MappedError<E> {
+    /// The zero-based index of the item that failed to map.
+    pub index: usize,
+    /// The error returned by the mapping function.
+    pub error: E,
+}
+
+impl<E> MappedError<E> {
+    pub fn new(index: usize, error: E) -> Self {
+        MappedError { index, error }
+    }
+
+    /// Consume the wrapper, returning the underlying error.
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+/// Implement `Display` for `MappedError`, prefixing the underlying error with the item index.
+impl<E> core::fmt::Display for MappedError<E>
+where
+    E: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "failed to map item {}: {}", self.index, self.error)
+    }
+}
+
+/// Implement `Error` for `MappedError`, exposing the underlying error as the source.
+#[cfg(feature = "std")]
+impl<E> std::error::Error for MappedError<E>
+where
+    E: std::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// An error raised while mapping an item, tagged with the name of the item type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypedError<E> {
+    /// The name of the type of the item that failed to map, as returned by `core::any::type_name`.
+    pub type_name: &'static str,
+    /// The error returned by the mapping function.
+    pub error: E,
+}
+
+impl<E> TypedError<E> {
+    pub fn new(type_name: &'static str, error: E) -> Self {
+        TypedError { type_name, error }
+    }
+
+    /// Consume the wrapper, returning the underlying error.
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+/// Implement `Display` for `TypedError`, prefixing the underlying error with the item type name.
+impl<E> core::fmt::Display for TypedError<E>
+where
+    E: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "failed to map item of type {}: {}",
+            self.type_name, self.error
+        )
+    }
+}
+
+/// Implement `Error` for `TypedError`, exposing the underlying error as the source.
+#[cfg(feature = "std")]
+impl<E> std::error::Error for TypedError<E>
+where
+    E: std::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// The kind of failure of a fallible consumer that needs at least one element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FallibleError<E> {
+    /// The mapping function returned an error.
+    Mapper(E),
+    /// The iterator yielded no elements.
+    Empty,
+}
+
+/// Implement `Display` for `FallibleError`, showing the mapper error or the missing elements.
+impl<E> core::fmt::Display for FallibleError<E>
+where
+    E: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FallibleError::Mapper(e) => e.fmt(f),
+            FallibleError::Empty => f.write_str("the iterator yielded no elements"),
+        }
+    }
+}
+
+/// Implement `Error` for `FallibleError`, exposing the mapper error as the source.
+#[cfg(feature = "std")]
+impl<E> std::error::Error for FallibleError<E>
+where
+    E: std::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FallibleError::Mapper(e) => Some(e),
+            FallibleError::Empty => None,
+        }
+    }
+}
+
+/// The failure of a call to a mapping function bounded by a timeout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimeoutError<E> {
+    /// The mapping function returned an error.
+    Mapper(E),
+    /// The mapping function did not return within the timeout.
+    Timeout,
+}
+
+/// Implement `Display` for `TimeoutError`, showing the mapper error or the timeout.
+impl<E> core::fmt::Display for TimeoutError<E>
+where
+    E: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TimeoutError::Mapper(e) => e.fmt(f),
+            TimeoutError::Timeout => f.write_str("the mapping function timed out"),
+        }
+    }
+}
+
+/// Implement `Error` for `TimeoutError`, exposing the mapper error as the source.
+#[cfg(feature = "std")]
+impl<E> std::error::Error for TimeoutError<E>
+where
+    E: std::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TimeoutError::Mapper(e) => Some(e),
+            TimeoutError::Timeout => None,
+        }
+    }
+}
+
+/// The failure of a round trip of a value through another type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RoundtripError<E> {
+    /// One of the conversion functions returned an error.
+    Mapper(E),
+    /// The value converted back differs from the original one.
+    Mismatch,
+}
+
+/// Implement `Display` for `RoundtripError`, showing the mapper error or the mismatch.
+impl<E> core::fmt::Display for RoundtripError<E>
+where
+    E: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RoundtripError::Mapper(e) => e.fmt(f),
+            RoundtripError::Mismatch => {
+                f.write_str("the round-tripped value differs from the original")
+            }
+        }
+    }
+}
+
+/// Implement `Error` for `RoundtripError`, exposing the mapper error as the source.
+#[cfg(feature = "std")]
+impl<E> std::error::Error for RoundtripError<E>
+where
+    E: std::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RoundtripError::Mapper(e) => Some(e),
+            RoundtripError::Mismatch => None,
+        }
+    }
+}
+
+/// A fallible map iterator that wraps each error with the position of the failing item.
+pub struct FallibleMapLocatedIterator<I, F, B, E> {
+    iter: I,
+    f: F,
+    index: usize,
+    _marker: core::marker::PhantomData<(B, E)>,
+}
+
+impl<I, F, B, E> FallibleMapLocatedIterator<I, F, B, E> {
+    pub fn new(iter: I, f: F) -> Self {
+        FallibleMapLocatedIterator {
+            iter,
+            f,
+            index: 0,
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+/// Implement `Iterator` for `FallibleMapLocatedIterator` where the iterator item is a located `Result`.
+impl<I, F, B, E> Iterator for FallibleMapLocatedIterator<I, F, B, E>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> Result<B, E>,
+{
+    type Item = Result<B, MappedError<E>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let index = self.index;
+
+        self.index += 1;
+
+        Some((self.f)(item).map_err(|e| MappedError::new(index, e)))
+    }
+}
+
+/// A fallible map iterator that tags each error with the name of the item type.
+pub struct FallibleMapTypedIterator<I, F, B, E> {
+    iter: I,
+    f: F,
+    type_name: &'static str,
+    _marker: core::marker::PhantomData<(B, E)>,
+}
+
+impl<I, F, B, E> FallibleMapTypedIterator<I, F, B, E>
+where
+    I: Iterator,
+{
+    pub fn new(iter: I, f: F) -> Self {
+        FallibleMapTypedIterator {
+            iter,
+            f,
+            type_name: core::any::type_name::<I::Item>(),
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+/// Implement `Iterator` for `FallibleMapTypedIterator` where the iterator item is a typed `Result`.
+impl<I, F, B, E> Iterator for FallibleMapTypedIterator<I, F, B, E>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> Result<B, E>,
+{
+    type Item = Result<B, TypedError<E>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let type_name = self.type_name;
+
+        Some((self.f)(self.iter.next()?).map_err(|e| TypedError::new(type_name, e)))
+    }
+}
+
+/// A fallible map iterator over lines read from IO, merging read errors with mapping errors.
+#[cfg(feature = "std")]
+pub struct FallibleMapLinesIterator<I, F, B, E> {
+    iter: I,
+    f: F,
+    _marker: core::marker::PhantomData<(B, E)>,
+}
+
+#[cfg(feature = "std")]
+impl<I, F, B, E> FallibleMapLinesIterator<I, F, B, E> {
+    pub fn new(iter: I, f: F) -> Self {
+        FallibleMapLinesIterator {
+            iter,
+            f,
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+/// Implement `Iterator` for `FallibleMapLinesIterator` where the iterator item is a `Result`.
+#[cfg(feature = "std")]
+impl<I, F, B, E> Iterator for FallibleMapLinesIterator<I, F, B, E>
+where
+    I: Iterator<Item = std::io::Result<String>>,
+    F: FnMut(String) -> Result<B, E>,
+    E: From<std::io::Error>,
+{
+    type Item = Result<B, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(match self.iter.next()? {
+            Ok(line) => (self.f)(line),
+            Err(e) => Err(E::from(e)),
+        })
+    }
+}
+
+/// A fallible map iterator driven by `ControlFlow`, which yields `Continue` values and stops on `Break`, keeping its value.
+pub struct FallibleMapControlIterator<I, F, B, C, E> {
+    iter: I,
+    f: F,
+    broken: Option<B>,
+    _marker: core::marker::PhantomData<(C, E)>,
+}
+
+impl<I, F, B, C, E> FallibleMapControlIterator<I, F, B, C, E> {
+    pub fn new(iter: I, f: F) -> Self {
+        FallibleMapControlIterator {
+            iter,
+            f,
+            broken: None,
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Return the value the mapping function broke with, if it did.
+    pub fn break_value(&self) -> Option<&B> {
+        self.broken.as_ref()
+    }
+
+    /// Consume the adapter, returning the value the mapping function broke with, if it did.
+    pub fn into_break(self) -> Option<B> {
+        self.broken
+    }
+}
+
+/// Implement `Iterator` for `FallibleMapControlIterator` where the iterator item is a `Result` of the `Continue` value.
+impl<I, F, B, C, E> Iterator for FallibleMapControlIterator<I, F, B, C, E>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> Result<core::ops::ControlFlow<B, C>, E>,
+{
+    type Item = Result<C, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.broken.is_some() {
+            return None;
+        }
+
+        match (self.f)(self.iter.next()?) {
+            Ok(core::ops::ControlFlow::Continue(x)) => Some(Ok(x)),
+            Ok(core::ops::ControlFlow::Break(x)) => {
+                self.broken = Some(x);
+                None
+            }
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+/// A fallible map iterator that pairs each mapped value with the item it was computed from.
+pub struct FallibleMapWithInputIterator<I, F, B, E> {
+    iter: I,
+    f: F,
+    _marker: core::marker::PhantomData<(B, E)>,
+}
+
+impl<I, F, B, E> FallibleMapWithInputIterator<I, F, B, E> {
+    pub fn new(iter: I, f: F) -> Self {
+        FallibleMapWithInputIterator {
+            iter,
+            f,
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+/// Implement `Iterator` for `FallibleMapWithInputIterator` where the iterator item is a `Result` of the input and output pair.
+impl<I, F, B, E> Iterator for FallibleMapWithInputIterator<I, F, B, E>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> Result<B, E>,
+{
+    type Item = Result<(I::Item, B), E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+
+        Some((self.f)(&item).map(|x| (item, x)))
+    }
+}
+
+/// The outcome of a fallible map that stopped at the first error, recording how far it got.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TryMapSummary<B, E> {
+    /// The values mapped before the first error, or all of them.
+    pub values: Vec<B>,
+    /// The first error returned by the mapping function, if any.
+    pub error: Option<E>,
+    /// The number of items handed to the mapping function, including the failing one.
+    pub processed: usize,
+}
+
+/// Extend iterator with fallible map functionality.
+pub trait FallibleMapIteratorExt: Iterator {
+    /// Attempt to map a function over an iterator, returning a `Result` iterator.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator where each item is a `Result<B, E>`.
+    fn try_map<B, F, E>(self, f: F) -> FallibleMapIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>;
+
+    /// Map a function over an iterator in parallel, using a pool of `std` threads.
+    ///
+    /// Items are handed out to the workers through a shared queue, and the mapped
+    /// values are returned in input order. Once an item fails, no further items
+    /// are handed out, and the error of the failing item with the lowest index is returned.
+    ///
+    /// # Parameters
+    ///
+    /// - `threads`: The number of worker threads, clamped to at least one.
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `Vec<B>` in input order, or the first error `E` by index.
+    #[cfg(feature = "threaded")]
+    fn par_try_map_threads<B, F, E>(self, threads: usize, f: F) -> Result<Vec<B>, E>
+    where
+        Self: Sized,
+        Self::Item: Send,
+        B: Send,
+        E: Send,
+        F: Fn(Self::Item) -> Result<B, E> + Sync;
+
+    /// Attempt to map a function over an iterator, measuring each call.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator where each item is a `Result<(B, Duration), E>`, pairing each
+    /// mapped value with the time spent computing it. Errors carry no duration.
+    #[cfg(feature = "std")]
+    fn try_map_timed<B, F, E>(self, f: F) -> FallibleMapTimedIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>;
+
+    /// Map and reduce an iterator in a single pass, using fallible functions for both steps.
+    ///
+    /// # Parameters
+    ///
+    /// - `init`: The initial value of the accumulator.
+    /// - `map`: A function that takes an item and returns a `Result<B, E>`.
+    /// - `reduce`: A function that takes the accumulator and a mapped value and returns a `Result<Acc, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the final accumulator, or the first error `E` from either function.
+    fn try_map_reduce<B, Acc, MF, RF, E>(self, init: Acc, map: MF, reduce: RF) -> Result<Acc, E>
+    where
+        Self: Sized,
+        MF: FnMut(Self::Item) -> Result<B, E>,
+        RF: FnMut(Acc, B) -> Result<Acc, E>;
+
+    /// Search for the position of the first item matching a fallible predicate.
+    ///
+    /// Iteration stops at the first match or the first error, so the iterator
+    /// can be resumed afterwards.
+    ///
+    /// # Parameters
+    ///
+    /// - `predicate`: A function that takes an item and returns a `Result<bool, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the zero-based index of the first matching item,
+    /// `None` if no item matches, or the first error `E`.
+    fn try_position<F, E>(&mut self, predicate: F) -> Result<Option<usize>, E>
+    where
+        F: FnMut(Self::Item) -> Result<bool, E>;
+
+    /// Attempt to expand each item of an iterator into several values.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes an item and returns a `Result<Vec<B>, E>`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator yielding each produced value as `Ok(B)`, or `Err(E)` in place
+    /// of an item whose expansion failed.
+    fn try_expand<B, F, E>(self, f: F) -> FallibleExpandIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<Vec<B>, E>;
+
+    /// Attempt to map a function over an iterator, collecting into a boxed slice.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `Box<[B]>`, or the first error `E`.
+    fn try_collect_boxed<B, F, E>(self, f: F) -> Result<Box<[B]>, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>;
+
+    /// Attempt to map a function over an iterator, collecting every distinct error.
+    ///
+    /// Every item is processed; duplicate errors are collapsed into one, keeping
+    /// the order in which each error was first seen.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `Vec<B>` if every item succeeds, or a `Vec<E>` of the distinct errors.
+    #[cfg(feature = "std")]
+    fn try_map_collect_unique_errors<B, F, E>(self, f: F) -> Result<Vec<B>, Vec<E>>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+        E: Clone + Eq + core::hash::Hash;
+
+    /// Attempt to map a function over an iterator, locating each error by item index.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator where each item is a `Result<B, MappedError<E>>`.
+    fn try_map_located<B, F, E>(self, f: F) -> FallibleMapLocatedIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>;
+
+    /// Map a function over every item, reporting each failure alongside its index.
+    ///
+    /// No error aborts the iteration.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing every mapped value, and every error paired with the
+    /// zero-based index of the failing item, both in input order.
+    fn try_map_report<B, F, E>(self, f: F) -> (Vec<B>, Vec<(usize, E)>)
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>;
+
+    /// Attempt to map a function over lines read from IO, such as `io::Lines`.
+    ///
+    /// A read error is converted into `E` with `From`, while a mapping error is
+    /// yielded as-is.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes a line and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator where each item is a `Result<B, E>`.
+    #[cfg(feature = "std")]
+    fn try_map_lines<B, F, E>(self, f: F) -> FallibleMapLinesIterator<Self, F, B, E>
+    where
+        Self: Sized + Iterator<Item = std::io::Result<String>>,
+        F: FnMut(String) -> Result<B, E>,
+        E: From<std::io::Error>;
+
+    /// Attempt to map a function returning a `ControlFlow` over an iterator.
+    ///
+    /// `Continue` values are yielded, errors are yielded as-is, and the first
+    /// `Break` ends the iteration. The break value can then be retrieved with
+    /// `FallibleMapControlIterator::break_value` or `FallibleMapControlIterator::into_break`.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes an item and returns a `Result<ControlFlow<B, C>, E>`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator where each item is a `Result<C, E>`.
+    fn try_map_control<B, C, F, E>(self, f: F) -> FallibleMapControlIterator<Self, F, B, C, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<core::ops::ControlFlow<B, C>, E>;
+
+    /// Attempt to map a function over an iterator, keeping each item alongside its mapped value.
+    ///
+    /// The function borrows the item, so no clone is needed to hand it back.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes a reference to an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator where each item is a `Result<(Self::Item, B), E>`.
+    fn try_map_with_input<B, F, E>(self, f: F) -> FallibleMapWithInputIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> Result<B, E>;
+
+    /// Apply a fallible function to each item along with its index, stopping at the first error.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes the zero-based index and an item and returns a `Result<(), E>`.
+    ///
+    /// # Returns
+    ///
+    /// An empty `Result`, or the first error `E`.
+    fn try_for_each_indexed<F, E>(self, f: F) -> Result<(), E>
+    where
+        Self: Sized,
+        F: FnMut(usize, Self::Item) -> Result<(), E>;
+
+    /// Consume an iterator, applying a fallible function to each item and discarding the results.
+    ///
+    /// Behaves like `Iterator::try_for_each`, named for "consume and discard"
+    /// loops such as cleanups.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes an item and returns a `Result<(), E>`.
+    ///
+    /// # Returns
+    ///
+    /// An empty `Result`, or the first error `E`.
+    fn try_drain<F, E>(self, f: F) -> Result<(), E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<(), E>;
+
+    /// Reduce an iterator to a single item using a fallible function.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes the accumulated item and the next item and returns a `Result<Self::Item, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the reduced item, `FallibleError::Empty` if the
+    /// iterator yielded no elements, or `FallibleError::Mapper` with the first error `E`.
+    fn try_reduce<F, E>(self, f: F) -> Result<Self::Item, FallibleError<E>>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item, Self::Item) -> Result<Self::Item, E>;
+
+    /// Attempt to map a function over the first item of an iterator.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the mapped first item, `FallibleError::Empty` if the
+    /// iterator yielded no elements, or `FallibleError::Mapper` with the error `E`.
+    fn try_first<B, F, E>(self, f: F) -> Result<B, FallibleError<E>>
+    where
+        Self: Sized,
+        F: FnOnce(Self::Item) -> Result<B, E>;
+
+    /// Apply a fallible function to each item by reference to the iterator, stopping at the first error.
+    ///
+    /// The iterator is only borrowed, so after an error it can be inspected and
+    /// consumption resumed from the item following the failing one.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes an item and returns a `Result<(), E>`.
+    ///
+    /// # Returns
+    ///
+    /// An empty `Result`, or the first error `E`.
+    fn try_for_each_ref<F, E>(&mut self, f: F) -> Result<(), E>
+    where
+        F: FnMut(Self::Item) -> Result<(), E>;
+
+    /// Attempt to map a function over an iterator, waiting at least `min_interval` between calls.
+    ///
+    /// The current thread sleeps as needed before each call. Failed calls count
+    /// toward the timing like successful ones.
+    ///
+    /// # Parameters
+    ///
+    /// - `min_interval`: The minimum time between the starts of two consecutive calls.
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator where each item is a `Result<B, E>`.
+    #[cfg(feature = "std")]
+    fn try_map_throttled<B, F, E>(
+        self,
+        min_interval: std::time::Duration,
+        f: F,
+    ) -> FallibleMapThrottledIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>;
+
+    /// Attempt to map a function over an iterator until a deadline passes.
+    ///
+    /// The deadline is checked before each item is pulled, so a call already in
+    /// progress is never interrupted; iteration simply ends once
+    /// `Instant::now() >= deadline`, leaving the remaining items unprocessed.
+    ///
+    /// # Parameters
+    ///
+    /// - `deadline`: The instant after which no more items are processed.
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator where each item is a `Result<B, E>`.
+    #[cfg(feature = "std")]
+    fn try_map_deadline<B, F, E>(
+        self,
+        deadline: std::time::Instant,
+        f: F,
+    ) -> FallibleMapDeadlineIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>;
+
+    /// Attempt to map a function over an iterator, keeping the successful prefix on error.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the values mapped before the first error, and that error, if any.
+    fn try_collect_with_first_error<B, F, E>(self, f: F) -> (Vec<B>, Option<E>)
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>;
+
+    /// Attempt to map a function over an iterator, bounding each call by a timeout.
+    ///
+    /// Each call runs on a freshly spawned worker thread, while the iterating
+    /// thread waits up to `per_item` for its result. A call that takes longer
+    /// yields `TimeoutError::Timeout` and iteration moves on to the next item;
+    /// the timed-out worker is detached and keeps running to completion in the
+    /// background, its result discarded. A panic in a worker is propagated to
+    /// the iterating thread.
+    ///
+    /// # Parameters
+    ///
+    /// - `per_item`: The maximum time to wait for each call.
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`; it is shared by the workers.
+    ///
+    /// # Returns
+    ///
+    /// An iterator where each item is a `Result<B, TimeoutError<E>>`.
+    #[cfg(feature = "std")]
+    fn try_map_with_timeout<B, F, E>(
+        self,
+        per_item: std::time::Duration,
+        f: F,
+    ) -> FallibleMapTimeoutIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        Self::Item: Send + 'static,
+        F: Fn(Self::Item) -> Result<B, E> + Send + Sync + 'static,
+        B: Send + 'static,
+        E: Send + 'static;
+
+    /// Attempt to map a function over an iterator on Tokio's blocking thread pool.
+    ///
+    /// The whole iteration runs inside a single `tokio::task::spawn_blocking`
+    /// task, started when the returned future is first polled, which must
+    /// therefore happen within a Tokio runtime. If the task fails to complete,
+    /// for example because the mapping function panicked, the `JoinError` is
+    /// converted into `E` with `From`.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A future resolving to a `Result` containing a `Vec<B>`, or the first error `E`.
+    #[cfg(feature = "tokio")]
+    fn try_map_spawn_blocking<B, F, E>(
+        self,
+        f: F,
+    ) -> impl core::future::Future<Output = Result<Vec<B>, E>> + Send
+    where
+        Self: Sized + Send + 'static,
+        F: FnMut(Self::Item) -> Result<B, E> + Send + 'static,
+        B: Send + 'static,
+        E: From<tokio::task::JoinError> + Send + 'static;
+
+    /// Attempt to map a function over an iterator, collecting non-fatal warnings on the side.
+    ///
+    /// Warnings are gathered from every call made, including those made before
+    /// an error stops the iteration.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes an item and returns a `Result<(B, Option<W>), E>`.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing a `Result` with a `Vec<B>` or the first error `E`,
+    /// and every warning `W` in input order.
+    fn try_map_warn<B, F, W, E>(self, f: F) -> (Result<Vec<B>, E>, Vec<W>)
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<(B, Option<W>), E>;
+
+    /// Attempt to map a function producing a nested collection over an iterator, without flattening.
+    ///
+    /// Each inner collection is handed back as its iterator, left unconsumed so
+    /// it can be processed lazily. An error from `f` takes the place of the
+    /// inner iterator for that item.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes an item and returns a `Result<J, E>`, where `J: IntoIterator`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator where each item is a `Result<J::IntoIter, E>`.
+    fn try_map_nested<J, F, E>(self, f: F) -> FallibleMapNestedIterator<Self, F, J, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<J, E>,
+        J: IntoIterator;
+
+    /// Merge two sorted iterators and map a function over the merged items, collecting into a `Vec`.
+    ///
+    /// The merge is stable: when `cmp` finds two items equal, the one from
+    /// `self` comes first.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: Another source of items, sorted according to `cmp`.
+    /// - `cmp`: A function comparing two items.
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `Vec<B>` in merged order, or the first error `E`.
+    fn try_merge_map<B, J, C, F, E>(self, other: J, cmp: C, f: F) -> Result<Vec<B>, E>
+    where
+        Self: Sized,
+        J: IntoIterator<Item = Self::Item>,
+        C: FnMut(&Self::Item, &Self::Item) -> core::cmp::Ordering,
+        F: FnMut(Self::Item) -> Result<B, E>;
+
+    /// Attempt to map a function over an iterator, hashing every successfully mapped value.
+    ///
+    /// Each `Ok` value is fed into `hasher` through its `Hash` implementation,
+    /// in order, while errors leave the hash untouched. The running hash is
+    /// available from `FallibleMapHashedIterator::finish_hash`.
+    ///
+    /// # Parameters
+    ///
+    /// - `hasher`: The `Hasher` accumulating the mapped values.
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator where each item is a `Result<B, E>`.
+    fn try_map_hashed<B, F, H, E>(
+        self,
+        hasher: H,
+        f: F,
+    ) -> FallibleMapHashedIterator<Self, F, H, B, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+        H: core::hash::Hasher,
+        B: core::hash::Hash;
+
+    /// Attempt to map a function over an iterator, replacing every error with a default value.
+    ///
+    /// # Parameters
+    ///
+    /// - `default`: The value yielded, cloned, in place of each error.
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator where each item is a `B`.
+    fn try_map_or_value<B, F, E>(
+        self,
+        default: B,
+        f: F,
+    ) -> FallibleMapOrValueIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+        B: Clone;
+
+    /// Attempt to map a function over an iterator, handing each error to a recovery function.
+    ///
+    /// If the recovery function fails as well, its error is yielded and the iteration ends.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    /// - `recover`: A function that takes an error and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator where each item is a `Result<B, E>`.
+    fn try_map_or_else_item<B, F, R, E>(
+        self,
+        f: F,
+        recover: R,
+    ) -> FallibleMapOrElseIterator<Self, F, R, B, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+        R: FnMut(E) -> Result<B, E>;
+
+    /// Attempt to map a function over an iterator, collecting the values into a fixed-capacity `ArrayVec`.
+    ///
+    /// Producing more than `N` values is reported through `E::from`, with the
+    /// first value that did not fit, and stops the iteration.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an `ArrayVec<B, N>`, or the first error `E`.
+    #[cfg(feature = "arrayvec")]
+    fn try_collect_arrayvec<const N: usize, B, F, E>(
+        self,
+        f: F,
+    ) -> Result<arrayvec::ArrayVec<B, N>, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+        E: From<arrayvec::CapacityError<B>>;
+
+    /// Attempt to map a function over an iterator, logging each error at the `error` level.
+    ///
+    /// Errors are still yielded unchanged, so control flow is not altered.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator where each item is a `Result<B, E>`.
+    #[cfg(feature = "log")]
+    fn try_map_logged<B, F, E>(self, f: F) -> FallibleMapLoggedIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+        E: core::fmt::Display;
+
+    /// Attempt to map a function over an iterator, reporting progress after each item.
+    ///
+    /// `progress` is called with the number of items mapped so far after each
+    /// successful call to `f`; the failing item, if any, is not reported.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    /// - `progress`: A function that takes the count of items processed so far.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `Vec<B>`, or the first error `E`.
+    fn try_map_progress<B, F, P, E>(self, f: F, progress: P) -> Result<Vec<B>, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+        P: FnMut(usize);
+
+    /// Attempt to map a function over an iterator, distinguishing complete from partial outcomes.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// `Left` with every mapped value if all calls succeeded, or `Right` with the
+    /// values mapped before the first error, together with that error.
+    #[cfg(feature = "either")]
+    fn try_map_either<B, F, E>(self, f: F) -> either::Either<Vec<B>, (Vec<B>, E)>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>;
+
+    /// Attempt to map a function over an iterator, grouping the values into pages.
+    ///
+    /// Every page holds `page_size` values, except possibly the last one.
+    ///
+    /// # Parameters
+    ///
+    /// - `page_size`: The number of values per page.
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `Vec<Vec<B>>` of pages, or the first error `E`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `page_size` is 0.
+    fn try_map_paged<B, F, E>(self, page_size: usize, f: F) -> Result<Vec<Vec<B>>, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>;
+
+    /// Attempt to map a function over overlapping windows of consecutive items.
+    ///
+    /// Each window holds `size` items and starts one item after the previous
+    /// one; an iterator with fewer than `size` items yields nothing. Use
+    /// `FallibleWindowsIterator::with_ranges` to tag each value with the range
+    /// of input positions it was computed from.
+    ///
+    /// # Parameters
+    ///
+    /// - `size`: The number of items per window.
+    /// - `f`: A function that takes a window of items and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator where each item is a `Result<B, E>`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    fn try_windows<B, F, E>(self, size: usize, f: F) -> FallibleWindowsIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        F: FnMut(&[Self::Item]) -> Result<B, E>;
+
+    /// Attempt to map a function over an iterator, cleaning up the values already mapped on error.
+    ///
+    /// If `f` fails, `cleanup` is called on each previously mapped value, in
+    /// order, before the error is returned. On success, `cleanup` is not called.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    /// - `cleanup`: A function that takes a reference to a mapped value to release it.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `Vec<B>`, or the first error `E`.
+    fn try_map_with_cleanup<B, F, C, E>(self, f: F, cleanup: C) -> Result<Vec<B>, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+        C: FnMut(&B);
+
+    /// Attempt to map a function over an iterator, summarizing how far it got before the first error.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `TryMapSummary` with the mapped values, the first error, if any, and
+    /// the number of items processed.
+    fn try_map_summarize<B, F, E>(self, f: F) -> TryMapSummary<B, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>;
+
+    /// Attempt to map a function over an iterator, returning the adapter as a boxed trait object.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Box<dyn Iterator>` where each item is a `Result<B, E>`.
+    fn try_map_boxed<B, F, E>(self, f: F) -> Box<dyn Iterator<Item = Result<B, E>>>
+    where
+        Self: Sized + 'static,
+        F: FnMut(Self::Item) -> Result<B, E> + 'static,
+        B: 'static,
+        E: 'static;
+
+    /// Attempt to map a function over an iterator, sending each mapped value on a channel.
+    ///
+    /// The first error ends the iteration and is returned to the caller, and the
+    /// channel closes once `tx` is dropped. If the receiver has hung up, the
+    /// iteration ends early without an error.
+    ///
+    /// # Parameters
+    ///
+    /// - `tx`: The sending half of the channel the mapped values are sent on.
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing `()`, or the first error `E`.
+    #[cfg(feature = "std")]
+    fn try_map_send<B, F, E>(self, tx: std::sync::mpsc::Sender<B>, f: F) -> Result<(), E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>;
+
+    /// Map a fallible function over every item, routing values and errors to separate channels.
+    ///
+    /// Errors do not stop the iteration. Messages for a receiver that has hung up are dropped.
+    ///
+    /// # Parameters
+    ///
+    /// - `ok_tx`: The sending half of the channel the mapped values are sent on.
+    /// - `err_tx`: The sending half of the channel the errors are sent on.
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    #[cfg(feature = "std")]
+    fn try_map_split_channels<B, F, E>(
+        self,
+        ok_tx: std::sync::mpsc::Sender<B>,
+        err_tx: std::sync::mpsc::Sender<E>,
+        f: F,
+    ) where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>;
+
+    /// Map a fallible function over every item, counting the successes and failures.
+    ///
+    /// Both the values and the errors are discarded, and errors do not stop the iteration.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the number of successes and the number of failures.
+    fn try_map_tally<B, F, E>(self, f: F) -> (usize, usize)
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>;
+
+    /// Attempt to map a function over an iterator, retrying failing items with exponential backoff.
+    ///
+    /// After the `n`-th failed attempt on an item, starting from 0, the adapter
+    /// sleeps for `base * 2^n` before trying again, up to `max_retries` times.
+    /// The last error is yielded if every attempt fails.
+    ///
+    /// # Parameters
+    ///
+    /// - `base`: The sleep before the first retry.
+    /// - `max_retries`: The maximum number of retries per item.
+    /// - `f`: A function that takes a reference to an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator where each item is a `Result<B, E>`.
+    #[cfg(feature = "std")]
+    fn try_map_backoff<B, F, E>(
+        self,
+        base: std::time::Duration,
+        max_retries: usize,
+        f: F,
+    ) -> FallibleMapBackoffIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> Result<B, E>;
+
+    /// Attempt to map a function over an iterator, tracing each call to the mapping function.
+    ///
+    /// Each call runs inside an `INFO` span named `try_map`, whose `name` field
+    /// holds `span_name` and whose `index` field holds the item position. A
+    /// `DEBUG` event is recorded for each success and an `ERROR` event, with the
+    /// error in its `error` field, for each failure.
+    ///
+    /// # Parameters
+    ///
+    /// - `span_name`: The name recorded on each span.
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator where each item is a `Result<B, E>`.
+    #[cfg(feature = "tracing")]
+    fn try_map_traced<B, F, E>(
+        self,
+        span_name: &'static str,
+        f: F,
+    ) -> FallibleMapTracedIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+        E: core::fmt::Display;
+
+    /// Attempt to map a function over an iterator of references, handing it cloned values.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes an owned clone of an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator where each item is a `Result<B, E>`.
+    fn try_map_cloned<'a, T, B, F, E>(
+        self,
+        f: F,
+    ) -> FallibleMapIterator<core::iter::Cloned<Self>, F, B, E>
+    where
+        Self: Sized + Iterator<Item = &'a T>,
+        T: Clone + 'a,
+        F: FnMut(T) -> Result<B, E>;
+
+    /// Attempt to map a function over an iterator, aborting on fatal errors and collecting the others.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    /// - `is_fatal`: A function that takes a reference to an error and returns whether it is fatal.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the mapped values and the recoverable errors, each
+    /// in input order, or the first fatal error `E`.
+    fn try_map_classified<B, F, C, E>(self, f: F, is_fatal: C) -> Result<(Vec<B>, Vec<E>), E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+        C: Fn(&E) -> bool;
+
+    /// Attempt to map a function over consecutive windows of items, reporting every error per window.
+    ///
+    /// Each window holds `window` items, except possibly the last one. Errors do
+    /// not stop the iteration; a window with any error yields its values
+    /// together with all of its errors.
+    ///
+    /// # Parameters
+    ///
+    /// - `window`: The number of items per window.
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator where each item is a `Result<Vec<B>, (Vec<B>, Vec<E>)>`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is 0.
+    fn try_map_error_windows<B, F, E>(
+        self,
+        window: usize,
+        f: F,
+    ) -> FallibleMapErrorWindowsIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>;
+
+    /// Attempt to map a function over an iterator, writing the values into a pre-allocated slice.
+    ///
+    /// At most `out.len()` items are taken from the iterator. On error, the
+    /// values written before it are left in place.
+    ///
+    /// # Parameters
+    ///
+    /// - `out`: The slice the mapped values are written into, from the start.
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the number of values written, or the first error `E`.
+    fn try_map_into_slice<B, F, E>(self, out: &mut [B], f: F) -> Result<usize, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>;
+
+    /// Attempt to map a function over an iterator, letting it end the iteration through a `Step`.
+    ///
+    /// Errors are yielded without ending the iteration.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes an item and returns a `Result<Step<B>, E>`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator where each item is a `Result<B, E>`.
+    fn try_map_until<B, F, E>(self, f: F) -> FallibleMapUntilIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<Step<B>, E>;
+
+    /// Attempt to map a function over the items not yet recorded in a set, recording each success.
+    ///
+    /// Items already in `seen` are skipped. An item is inserted once it has been
+    /// mapped successfully, so a failing item is retried when it comes up again,
+    /// and the set can be kept across runs to avoid reprocessing.
+    ///
+    /// # Parameters
+    ///
+    /// - `seen`: The set of items already processed.
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator where each item is a `Result<B, E>`.
+    #[cfg(feature = "std")]
+    fn try_map_dedup_persist<B, F, E>(
+        self,
+        seen: &mut std::collections::HashSet<Self::Item>,
+        f: F,
+    ) -> FallibleMapDedupPersistIterator<'_, Self, F, B, E>
+    where
+        Self: Sized,
+        Self::Item: Eq + core::hash::Hash + Clone,
+        F: FnMut(Self::Item) -> Result<B, E>;
+
+    /// Attempt to map a function over an iterator, retrying failing items and counting the attempts.
+    ///
+    /// Each item is tried up to `max` times, at least once. The last error is
+    /// yielded if every attempt fails.
+    ///
+    /// # Parameters
+    ///
+    /// - `max`: The maximum number of attempts per item.
+    /// - `f`: A function that takes a reference to an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator where each item is a `Result<(B, usize), E>`, pairing each
+    /// mapped value with the number of attempts it took.
+    fn try_map_with_attempts<B, F, E>(
+        self,
+        max: usize,
+        f: F,
+    ) -> FallibleMapAttemptsIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> Result<B, E>;
+
+    /// Attempt to build a command for each item, running it and collecting the outputs.
+    ///
+    /// A command exiting with a non-zero status is not an error; inspect the
+    /// `status` of each output instead.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes an item and returns a `Result<Command, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `Vec<Output>`, or the first error `E`, either
+    /// from building a command or from spawning it.
+    #[cfg(feature = "std")]
+    fn try_map_command<F, E>(self, f: F) -> Result<Vec<std::process::Output>, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<std::process::Command, E>,
+        E: From<std::io::Error>;
+
+    /// Attempt to map a function over an iterator, or only walk the items in a dry run.
+    ///
+    /// # Parameters
+    ///
+    /// - `dry_run`: Whether to skip calling `f`, yielding a `None` placeholder for each item.
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `Vec<Option<B>>` with a slot per item, `Some` when
+    /// `f` was called and `None` in a dry run, or the first error `E`.
+    fn try_map_dry_run<B, F, E>(self, dry_run: bool, f: F) -> Result<Vec<Option<B>>, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>;
+
+    /// Attempt to map a function over an iterator, handing it a mutable random number generator.
+    ///
+    /// The generator can be any type; no particular random number crate is required.
+    ///
+    /// # Parameters
+    ///
+    /// - `rng`: The generator passed to every call of `f`.
+    /// - `f`: A function that takes the generator and an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `Vec<B>`, or the first error `E`.
+    fn try_map_with_rng<R, B, F, E>(self, rng: &mut R, f: F) -> Result<Vec<B>, E>
+    where
+        Self: Sized,
+        F: FnMut(&mut R, Self::Item) -> Result<B, E>;
+
+    /// Attempt to map a function over an iterator, reporting how many items were left unprocessed.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing a `Result` with a `Vec<B>` or the first error `E`, and
+    /// the number of items left in the iterator after it stopped, which is 0 on success.
+    fn try_collect_with_remaining<B, F, E>(self, f: F) -> (Result<Vec<B>, E>, usize)
+    where
+        Self: Sized + ExactSizeIterator,
+        F: FnMut(Self::Item) -> Result<B, E>;
+
+    /// Attempt to map a function over an iterator, deferring the terminating error.
+    ///
+    /// The values stop at the first error, which is stored in the returned
+    /// `DeferredError` and can be checked once the values have been consumed.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing an iterator where each item is a `B`, and the `DeferredError` it stops at.
+    fn try_map_deferred<B, F, E>(
+        self,
+        f: F,
+    ) -> (FallibleMapDeferredIterator<Self, F, B, E>, DeferredError<E>)
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>;
+
+    /// Attempt to map a function over an iterator, requiring the keys of the mapped values to be unique.
+    ///
+    /// # Parameters
+    ///
+    /// - `key`: A function that takes a reference to a mapped value and returns its key.
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    /// - `on_dup`: A function that takes the first repeated key and returns the error `E`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `Vec<B>`, or the first error `E`, either from `f`
+    /// or for a repeated key.
+    #[cfg(feature = "std")]
+    fn try_map_unique<K, B, KF, F, D, E>(self, key: KF, f: F, on_dup: D) -> Result<Vec<B>, E>
+    where
+        Self: Sized,
+        K: Eq + core::hash::Hash,
+        KF: FnMut(&B) -> K,
+        F: FnMut(Self::Item) -> Result<B, E>,
+        D: FnOnce(&K) -> E;
+
+    /// Attempt to map a function over an iterator, writing the bytes of each value and flushing periodically.
+    ///
+    /// The writer is flushed after every `n` items, and once more at the end;
+    /// with `n` set to 0, only at the end. Nothing is flushed after an error.
+    ///
+    /// # Parameters
+    ///
+    /// - `writer`: The writer the mapped bytes are written to.
+    /// - `n`: The number of items between flushes.
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`, where `B: AsRef<[u8]>`.
+    ///
+    /// # Returns
+    ///
+    /// An empty `Result`, or the first error `E`, from `f` or from the writer.
+    #[cfg(feature = "std")]
+    fn try_map_flush_every<B, F, W, E>(self, writer: &mut W, n: usize, f: F) -> Result<(), E>
+    where
+        Self: Sized,
+        B: AsRef<[u8]>,
+        F: FnMut(Self::Item) -> Result<B, E>,
+        W: std::io::Write,
+        E: From<std::io::Error>;
+
+    /// Attempt to map a function over an iterator, gathering errors until `limit` of them are reached.
+    ///
+    /// Errors do not stop the iteration until `limit` of them, at least one,
+    /// have been gathered; the remaining items are then left unprocessed.
+    ///
+    /// # Parameters
+    ///
+    /// - `limit`: The maximum number of errors to gather.
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `Vec<B>` if every item is mapped successfully, or
+    /// a `Vec<E>` of the errors gathered, in input order.
+    fn try_map_first_errors<B, F, E>(self, limit: usize, f: F) -> Result<Vec<B>, Vec<E>>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>;
+
+    /// Attempt to map a function over an iterator, tagging each error with the name of the item type.
+    ///
+    /// The type name is computed once, when the adapter is created.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator where each item is a `Result<B, TypedError<E>>`.
+    fn try_map_typed_errors<B, F, E>(self, f: F) -> FallibleMapTypedIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>;
+}
+
+/// Implementation of `FallibleMapIteratorExt` for all iterators.
+impl<I> FallibleMapIteratorExt for I
+where
+    I: Iterator,
+{
+    fn try_map<B, F, E>(self, f: F) -> FallibleMapIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+    {
+        FallibleMapIterator::new(self, f)
+    }
+
+    #[cfg(feature = "threaded")]
+    fn par_try_map_threads<B, F, E>(self, threads: usize, f: F) -> Result<Vec<B>, E>
+    where
+        Self: Sized,
+        Self::Item: Send,
+        B: Send,
+        E: Send,
+        F: Fn(Self::Item) -> Result<B, E> + Sync,
+    {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Mutex;
+
+        let items: Vec<_> = self.enumerate().collect();
+        let len = items.len();
+        let queue = Mutex::new(items.into_iter());
+        let failed = AtomicBool::new(false);
+        let (queue, failed, f) = (&queue, &failed, &f);
+
+        let outcomes: Vec<Vec<(usize, Result<B, E>)>> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..threads.max(1))
+                .map(|_| {
+                    scope.spawn(move || {
+                        let mut outcomes = Vec::new();
+
+                        while !failed.load(Ordering::Relaxed) {
+                            let next = queue.lock().unwrap_or_else(|e| e.into_inner()).next();
+
+                            let (index, item) = match next {
+                                Some(x) => x,
+                                None => break,
+                            };
+
+                            let result = f(item);
+
+                            if result.is_err() {
+                                failed.store(true, Ordering::Relaxed);
+                            }
+
+                            outcomes.push((index, result));
+                        }
+
+                        outcomes
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .map(|handle| {
+                    handle
+                        .join()
+                        .unwrap_or_else(|e| std::panic::resume_unwind(e))
+                })
+                .collect()
+        });
+
+        let mut values: Vec<Option<B>> = (0..len).map(|_| None).collect();
+        let mut error: Option<(usize, E)> = None;
+
+        for (index, result) in outcomes.into_iter().flatten() {
+            match result {
+                Ok(x) => values[index] = Some(x),
+                // `Option::is_none_or` would raise the minimum supported Rust version to 1.82.
+                #[allow(clippy::unnecessary_map_or)]
+                Err(e) => {
+                    if error.as_ref().map_or(true, |(first, _)| index < *first) {
+                        error = Some((index, e));
+                    }
+                }
+            }
+        }
+
+        match error {
+            Some((_, e)) => Err(e),
+            None => Ok(values.into_iter().flatten().collect()),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn try_map_timed<B, F, E>(self, f: F) -> FallibleMapTimedIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+    {
+        FallibleMapTimedIterator::new(self, f)
+    }
+
+    fn try_map_reduce<B, Acc, MF, RF, E>(
+        self,
+        init: Acc,
+        mut map: MF,
+        mut reduce: RF,
+    ) -> Result<Acc, E>
+    where
+        Self: Sized,
+        MF: FnMut(Self::Item) -> Result<B, E>,
+        RF: FnMut(Acc, B) -> Result<Acc, E>,
+    {
+        let mut acc = init;
+
+        for item in self {
+            acc = reduce(acc, map(item)?)?;
+        }
+
+        Ok(acc)
+    }
+
+    fn try_position<F, E>(&mut self, mut predicate: F) -> Result<Option<usize>, E>
+    where
+        F: FnMut(Self::Item) -> Result<bool, E>,
+    {
+        for (index, item) in self.enumerate() {
+            if predicate(item)? {
+                return Ok(Some(index));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn try_expand<B, F, E>(self, f: F) -> FallibleExpandIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<Vec<B>, E>,
+    {
+        FallibleExpandIterator::new(self, f)
+    }
+
+    fn try_collect_boxed<B, F, E>(self, f: F) -> Result<Box<[B]>, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+    {
+        self.try_map(f).collect()
+    }
+
+    #[cfg(feature = "std")]
+    fn try_map_collect_unique_errors<B, F, E>(self, f: F) -> Result<Vec<B>, Vec<E>>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+        E: Clone + Eq + core::hash::Hash,
+    {
+        let (values, errors) = self.try_map(f).partition_result();
+
+        if errors.is_empty() {
+            return Ok(values);
+        }
+
+        let mut seen = std::collections::HashSet::new();
+
+        Err(errors
+            .into_iter()
+            .filter(|e| seen.insert(e.clone()))
+            .collect())
+    }
+
+    fn try_map_located<B, F, E>(self, f: F) -> FallibleMapLocatedIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+    {
+        FallibleMapLocatedIterator::new(self, f)
+    }
+
+    fn try_map_report<B, F, E>(self, f: F) -> (Vec<B>, Vec<(usize, E)>)
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+    {
+        self.try_map_located(f)
+            .map(|x| x.map_err(|e| (e.index, e.error)))
+            .partition_result()
+    }
+
+    #[cfg(feature = "std")]
+    fn try_map_lines<B, F, E>(self, f: F) -> FallibleMapLinesIterator<Self, F, B, E>
+    where
+        Self: Sized + Iterator<Item = std::io::Result<String>>,
+        F: FnMut(String) -> Result<B, E>,
+        E: From<std::io::Error>,
+    {
+        FallibleMapLinesIterator::new(self, f)
+    }
+
+    fn try_map_control<B, C, F, E>(self, f: F) -> FallibleMapControlIterator<Self, F, B, C, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<core::ops::ControlFlow<B, C>, E>,
+    {
+        FallibleMapControlIterator::new(self, f)
+    }
+
+    fn try_map_with_input<B, F, E>(self, f: F) -> FallibleMapWithInputIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> Result<B, E>,
+    {
+        FallibleMapWithInputIterator::new(self, f)
+    }
+
+    fn try_for_each_indexed<F, E>(self, mut f: F) -> Result<(), E>
+    where
+        Self: Sized,
+        F: FnMut(usize, Self::Item) -> Result<(), E>,
+    {
+        for (index, item) in self.enumerate() {
+            f(index, item)?;
+        }
+
+        Ok(())
+    }
+
+    fn try_drain<F, E>(mut self, f: F) -> Result<(), E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<(), E>,
+    {
+        self.try_for_each(f)
+    }
+
+    fn try_reduce<F, E>(mut self, f: F) -> Result<Self::Item, FallibleError<E>>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item, Self::Item) -> Result<Self::Item, E>,
+    {
+        let first = self.next().ok_or(FallibleError::Empty)?;
+
+        self.try_fold(first, f).map_err(FallibleError::Mapper)
+    }
+
+    fn try_first<B, F, E>(mut self, f: F) -> Result<B, FallibleError<E>>
+    where
+        Self: Sized,
+        F: FnOnce(Self::Item) -> Result<B, E>,
+    {
+        let first = self.next().ok_or(FallibleError::Empty)?;
+
+        f(first).map_err(FallibleError::Mapper)
+    }
+
+    fn try_for_each_ref<F, E>(&mut self, mut f: F) -> Result<(), E>
+    where
+        F: FnMut(Self::Item) -> Result<(), E>,
+    {
+        for item in self {
+            f(item)?;
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "std")]
+    fn try_map_throttled<B, F, E>(
+        self,
+        min_interval: std::time::Duration,
+        f: F,
+    ) -> FallibleMapThrottledIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+    {
+        FallibleMapThrottledIterator::new(self, min_interval, f)
+    }
+
+    #[cfg(feature = "std")]
+    fn try_map_deadline<B, F, E>(
+        self,
+        deadline: std::time::Instant,
+        f: F,
+    ) -> FallibleMapDeadlineIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+    {
+        FallibleMapDeadlineIterator::new(self, deadline, f)
+    }
+
+    fn try_collect_with_first_error<B, F, E>(self, f: F) -> (Vec<B>, Option<E>)
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+    {
+        let mut iter = self.try_map(f).take_while_ok();
+        let values = iter.by_ref().collect();
+
+        (values, iter.into_err())
+    }
+
+    #[cfg(feature = "std")]
+    fn try_map_with_timeout<B, F, E>(
+        self,
+        per_item: std::time::Duration,
+        f: F,
+    ) -> FallibleMapTimeoutIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        Self::Item: Send + 'static,
+        F: Fn(Self::Item) -> Result<B, E> + Send + Sync + 'static,
+        B: Send + 'static,
+        E: Send + 'static,
+    {
+        FallibleMapTimeoutIterator::new(self, per_item, f)
+    }
+
+    #[cfg(feature = "tokio")]
+    async fn try_map_spawn_blocking<B, F, E>(self, f: F) -> Result<Vec<B>, E>
+    where
+        Self: Sized + Send + 'static,
+        F: FnMut(Self::Item) -> Result<B, E> + Send + 'static,
+        B: Send + 'static,
+        E: From<tokio::task::JoinError> + Send + 'static,
+    {
+        tokio::task::spawn_blocking(move || self.try_map(f).collect()).await?
+    }
+
+    fn try_map_warn<B, F, W, E>(self, f: F) -> (Result<Vec<B>, E>, Vec<W>)
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<(B, Option<W>), E>,
+    {
+        let mut warnings = Vec::new();
+        let values = self
+            .try_map(f)
+            .map_ok(|(x, warning)| {
+                warnings.extend(warning);
+                x
+            })
+            .collect();
+
+        (values, warnings)
+    }
+
+    fn try_map_nested<J, F, E>(self, f: F) -> FallibleMapNestedIterator<Self, F, J, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<J, E>,
+        J: IntoIterator,
+    {
+        FallibleMapNestedIterator::new(self, f)
+    }
+
+    fn try_merge_map<B, J, C, F, E>(self, other: J, mut cmp: C, mut f: F) -> Result<Vec<B>, E>
+    where
+        Self: Sized,
+        J: IntoIterator<Item = Self::Item>,
+        C: FnMut(&Self::Item, &Self::Item) -> core::cmp::Ordering,
+        F: FnMut(Self::Item) -> Result<B, E>,
+    {
+        let mut left = self.peekable();
+        let mut right = other.into_iter().peekable();
+        let mut values = Vec::new();
+
+        loop {
+            let take_left = match (left.peek(), right.peek()) {
+                (Some(l), Some(r)) => cmp(l, r) != core::cmp::Ordering::Greater,
+                (Some(_), None) => true,
+                (None, Some(_)) => false,
+                (None, None) => break,
+            };
+
+            let item = if take_left { left.next() } else { right.next() };
+
+            if let Some(item) = item {
+                values.push(f(item)?);
+            }
+        }
+
+        Ok(values)
+    }
+
+    fn try_map_hashed<B, F, H, E>(
+        self,
+        hasher: H,
+        f: F,
+    ) -> FallibleMapHashedIterator<Self, F, H, B, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+        H: core::hash::Hasher,
+        B: core::hash::Hash,
+    {
+        FallibleMapHashedIterator::new(self, hasher, f)
+    }
+
+    fn try_map_or_value<B, F, E>(
+        self,
+        default: B,
+        f: F,
+    ) -> FallibleMapOrValueIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+        B: Clone,
+    {
+        FallibleMapOrValueIterator::new(self, default, f)
+    }
+
+    fn try_map_or_else_item<B, F, R, E>(
+        self,
+        f: F,
+        recover: R,
+    ) -> FallibleMapOrElseIterator<Self, F, R, B, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+        R: FnMut(E) -> Result<B, E>,
+    {
+        FallibleMapOrElseIterator::new(self, f, recover)
+    }
+
+    #[cfg(feature = "arrayvec")]
+    fn try_collect_arrayvec<const N: usize, B, F, E>(
+        self,
+        mut f: F,
+    ) -> Result<arrayvec::ArrayVec<B, N>, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+        E: From<arrayvec::CapacityError<B>>,
+    {
+        let mut values = arrayvec::ArrayVec::new();
+
+        for item in self {
+            values.try_push(f(item)?)?;
+        }
+
+        Ok(values)
+    }
+
+    #[cfg(feature = "log")]
+    fn try_map_logged<B, F, E>(self, f: F) -> FallibleMapLoggedIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+        E: core::fmt::Display,
+    {
+        FallibleMapLoggedIterator::new(self, f)
+    }
+
+    fn try_map_progress<B, F, P, E>(self, mut f: F, mut progress: P) -> Result<Vec<B>, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+        P: FnMut(usize),
+    {
+        let mut values = Vec::new();
+
+        for item in self {
+            values.push(f(item)?);
+            progress(values.len());
+        }
+
+        Ok(values)
+    }
+
+    #[cfg(feature = "either")]
+    fn try_map_either<B, F, E>(self, f: F) -> either::Either<Vec<B>, (Vec<B>, E)>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+    {
+        match self.try_collect_with_first_error(f) {
+            (values, None) => either::Either::Left(values),
+            (values, Some(e)) => either::Either::Right((values, e)),
+        }
+    }
+
+    fn try_map_paged<B, F, E>(self, page_size: usize, mut f: F) -> Result<Vec<Vec<B>>, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+    {
+        assert!(page_size > 0, "page size must be non-zero");
+
+        let mut pages = Vec::new();
+        let mut page = Vec::with_capacity(page_size);
+
+        for item in self {
+            page.push(f(item)?);
+
+            if page.len() == page_size {
+                pages.push(core::mem::replace(&mut page, Vec::with_capacity(page_size)));
+            }
+        }
+
+        if !page.is_empty() {
+            pages.push(page);
+        }
+
+        Ok(pages)
+    }
+
+    fn try_windows<B, F, E>(self, size: usize, f: F) -> FallibleWindowsIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        F: FnMut(&[Self::Item]) -> Result<B, E>,
+    {
+        FallibleWindowsIterator::new(self, size, f)
+    }
+
+    fn try_map_with_cleanup<B, F, C, E>(self, f: F, cleanup: C) -> Result<Vec<B>, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+        C: FnMut(&B),
+    {
+        match self.try_collect_with_first_error(f) {
+            (values, None) => Ok(values),
+            (values, Some(e)) => {
+                values.iter().for_each(cleanup);
+                Err(e)
+            }
+        }
+    }
+
+    fn try_map_summarize<B, F, E>(self, f: F) -> TryMapSummary<B, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+    {
+        let (values, error) = self.try_collect_with_first_error(f);
+        let processed = values.len() + usize::from(error.is_some());
+
+        TryMapSummary {
+            values,
+            error,
+            processed,
+        }
+    }
+
+    fn try_map_boxed<B, F, E>(self, f: F) -> Box<dyn Iterator<Item = Result<B, E>>>
+    where
+        Self: Sized + 'static,
+        F: FnMut(Self::Item) -> Result<B, E> + 'static,
+        B: 'static,
+        E: 'static,
+    {
+        Box::new(self.try_map(f))
+    }
+
+    #[cfg(feature = "std")]
+    fn try_map_send<B, F, E>(self, tx: std::sync::mpsc::Sender<B>, mut f: F) -> Result<(), E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+    {
+        for item in self {
+            if tx.send(f(item)?).is_err() {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "std")]
+    fn try_map_split_channels<B, F, E>(
+        self,
+        ok_tx: std::sync::mpsc::Sender<B>,
+        err_tx: std::sync::mpsc::Sender<E>,
+        f: F,
+    ) where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+    {
+        for result in self.try_map(f) {
+            match result {
+                Ok(x) => ok_tx.send(x).ok(),
+                Err(e) => err_tx.send(e).ok(),
+            };
+        }
+    }
+
+    fn try_map_tally<B, F, E>(self, f: F) -> (usize, usize)
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+    {
+        self.try_map(f)
+            .fold((0, 0), |(ok, err), result| match result {
+                Ok(_) => (ok + 1, err),
+                Err(_) => (ok, err + 1),
+            })
+    }
+
+    #[cfg(feature = "std")]
+    fn try_map_backoff<B, F, E>(
+        self,
+        base: std::time::Duration,
+        max_retries: usize,
+        f: F,
+    ) -> FallibleMapBackoffIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> Result<B, E>,
+    {
+        FallibleMapBackoffIterator::new(self, base, max_retries, f)
+    }
+
+    #[cfg(feature = "tracing")]
+    fn try_map_traced<B, F, E>(
+        self,
+        span_name: &'static str,
+        f: F,
+    ) -> FallibleMapTracedIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+        E: core::fmt::Display,
+    {
+        FallibleMapTracedIterator::new(self, span_name, f)
+    }
+
+    fn try_map_cloned<'a, T, B, F, E>(
+        self,
+        f: F,
+    ) -> FallibleMapIterator<core::iter::Cloned<Self>, F, B, E>
+    where
+        Self: Sized + Iterator<Item = &'a T>,
+        T: Clone + 'a,
+        F: FnMut(T) -> Result<B, E>,
+    {
+        self.cloned().try_map(f)
+    }
+
+    fn try_map_classified<B, F, C, E>(self, f: F, is_fatal: C) -> Result<(Vec<B>, Vec<E>), E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+        C: Fn(&E) -> bool,
+    {
+        let mut values = Vec::new();
+        let mut errors = Vec::new();
+
+        for result in self.try_map(f) {
+            match result {
+                Ok(x) => values.push(x),
+                Err(e) if is_fatal(&e) => return Err(e),
+                Err(e) => errors.push(e),
+            }
+        }
+
+        Ok((values, errors))
+    }
+
+    fn try_map_error_windows<B, F, E>(
+        self,
+        window: usize,
+        f: F,
+    ) -> FallibleMapErrorWindowsIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+    {
+        FallibleMapErrorWindowsIterator::new(self, window, f)
+    }
+
+    fn try_map_into_slice<B, F, E>(self, out: &mut [B], mut f: F) -> Result<usize, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+    {
+        let mut written = 0;
+
+        for (slot, item) in out.iter_mut().zip(self) {
+            *slot = f(item)?;
+            written += 1;
+        }
+
+        Ok(written)
+    }
+
+    fn try_map_until<B, F, E>(self, f: F) -> FallibleMapUntilIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<Step<B>, E>,
+    {
+        FallibleMapUntilIterator::new(self, f)
+    }
+
+    #[cfg(feature = "std")]
+    fn try_map_dedup_persist<B, F, E>(
+        self,
+        seen: &mut std::collections::HashSet<Self::Item>,
+        f: F,
+    ) -> FallibleMapDedupPersistIterator<'_, Self, F, B, E>
+    where
+        Self: Sized,
+        Self::Item: Eq + core::hash::Hash + Clone,
+        F: FnMut(Self::Item) -> Result<B, E>,
+    {
+        FallibleMapDedupPersistIterator::new(self, seen, f)
+    }
+
+    fn try_map_with_attempts<B, F, E>(
+        self,
+        max: usize,
+        f: F,
+    ) -> FallibleMapAttemptsIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> Result<B, E>,
+    {
+        FallibleMapAttemptsIterator::new(self, max, f)
+    }
+
+    #[cfg(feature = "std")]
+    fn try_map_command<F, E>(self, f: F) -> Result<Vec<std::process::Output>, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<std::process::Command, E>,
+        E: From<std::io::Error>,
+    {
+        self.try_map(f)
+            .and_then_try(|mut command| command.output().map_err(E::from))
+            .collect()
+    }
+
+    fn try_map_dry_run<B, F, E>(self, dry_run: bool, f: F) -> Result<Vec<Option<B>>, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+    {
+        if dry_run {
+            Ok(self.map(|_| None).collect())
+        } else {
+            self.try_map(f).map_ok(Some).collect()
+        }
+    }
+
+    fn try_map_with_rng<R, B, F, E>(self, rng: &mut R, mut f: F) -> Result<Vec<B>, E>
+    where
+        Self: Sized,
+        F: FnMut(&mut R, Self::Item) -> Result<B, E>,
+    {
+        self.try_map(|item| f(rng, item)).collect()
+    }
+
+    fn try_collect_with_remaining<B, F, E>(mut self, f: F) -> (Result<Vec<B>, E>, usize)
+    where
+        Self: Sized + ExactSizeIterator,
+        F: FnMut(Self::Item) -> Result<B, E>,
+    {
+        let result = self.by_ref().try_map(f).collect();
+
+        (result, self.len())
+    }
+
+    fn try_map_deferred<B, F, E>(
+        self,
+        f: F,
+    ) -> (FallibleMapDeferredIterator<Self, F, B, E>, DeferredError<E>)
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+    {
+        FallibleMapDeferredIterator::new(self, f)
+    }
+
+    #[cfg(feature = "std")]
+    fn try_map_unique<K, B, KF, F, D, E>(
+        self,
+        mut key: KF,
+        mut f: F,
+        on_dup: D,
+    ) -> Result<Vec<B>, E>
+    where
+        Self: Sized,
+        K: Eq + core::hash::Hash,
+        KF: FnMut(&B) -> K,
+        F: FnMut(Self::Item) -> Result<B, E>,
+        D: FnOnce(&K) -> E,
+    {
+        let mut keys = std::collections::HashSet::new();
+        let mut values = Vec::new();
+
+        for item in self {
+            let value = f(item)?;
+            let k = key(&value);
+
+            if keys.contains(&k) {
+                return Err(on_dup(&k));
+            }
+
+            keys.insert(k);
+            values.push(value);
+        }
+
+        Ok(values)
+    }
+
+    #[cfg(feature = "std")]
+    fn try_map_flush_every<B, F, W, E>(self, writer: &mut W, n: usize, f: F) -> Result<(), E>
+    where
+        Self: Sized,
+        B: AsRef<[u8]>,
+        F: FnMut(Self::Item) -> Result<B, E>,
+        W: std::io::Write,
+        E: From<std::io::Error>,
+    {
+        for (index, result) in self.try_map(f).enumerate() {
+            writer.write_all(result?.as_ref())?;
+
+            if n > 0 && (index + 1) % n == 0 {
+                writer.flush()?;
+            }
+        }
+
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    fn try_map_first_errors<B, F, E>(self, limit: usize, f: F) -> Result<Vec<B>, Vec<E>>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+    {
+        let mut values = Vec::new();
+        let mut errors = Vec::new();
+
+        for result in self.try_map(f) {
+            match result {
+                Ok(x) => values.push(x),
+                Err(e) => {
+                    errors.push(e);
+
+                    if errors.len() >= limit {
+                        break;
+                    }
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(values)
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn try_map_typed_errors<B, F, E>(self, f: F) -> FallibleMapTypedIterator<Self, F, B, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<B, E>,
+    {
+        FallibleMapTypedIterator::new(self, f)
+    }
+}
+
+/// A builder collecting fallibly mapped values from several sources into a single `Vec`.
+///
+/// Each call to `extend_try` maps a whole source, short-circuiting on its
+/// first error, so one `Vec` is filled without intermediate allocations.
+pub struct TryCollector<B, E> {
+    values: Vec<B>,
+    _marker: core::marker::PhantomData<E>,
+}
+
+impl<B, E> TryCollector<B, E> {
+    pub fn new() -> Self {
+        TryCollector {
+            values: Vec::new(),
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Create an empty collector with space for at least `capacity` values.
+    pub fn with_capacity(capacity: usize) -> Self {
+        TryCollector {
+            values: Vec::with_capacity(capacity),
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Map a function over a source, appending the mapped values.
+    ///
+    /// Space is reserved up front from the source's size hint.
+    ///
+    /// # Parameters
+    ///
+    /// - `iter`: The source to map over.
+    /// - `f`: A function that takes an item and returns a `Result<B, E>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the collector, or the first error `E`.
+    pub fn extend_try<I, F>(mut self, iter: I, f: F) -> Result<Self, E>
+    where
+        I: IntoIterator,
+        F: FnMut(I::Item) -> Result<B, E>,
+    {
+        let iter = iter.into_iter();
+
+        self.values.reserve(iter.size_hint().0);
+
+        for x in iter.try_map(f) {
+            self.values.push(x?);
+        }
+
+        Ok(self)
+    }
+
+    /// Consume the collector, returning the collected values.
+    pub fn finish(self) -> Vec<B> {
+        self.values
+    }
+}
+
+impl<B, E> Default for TryCollector<B, E> {
+    fn default() -> Self {
+        TryCollector::new()
+    }
+}
+
+/// Attempt to map a function over any collection, collecting into a `Vec`.
+///
+/// # Parameters
+///
+/// - `collection`: Any `IntoIterator`, such as a `Vec`, an array or a `HashSet`.
+/// - `f`: A function that takes an item and returns a `Result<U, E>`.
+///
+/// # Returns
+///
+/// A `Result` containing a `Vec<U>`, or the first error `E`.
+pub fn try_map_all<C, U, F, E>(collection: C, f: F) -> Result<Vec<U>, E>
+where
+    C: IntoIterator,
+    F: FnMut(C::Item) -> Result<U, E>,
+{
+    collection.into_iter().try_map(f).collect()
+}
+
+/// Collect the present results of a collection of optional `Result`s into a `Vec`.
+///
+/// `None` items are skipped, and the first `Some(Err(_))` stops the collection.
+///
+/// # Parameters
+///
+/// - `collection`: Any `IntoIterator` of `Option<Result<T, E>>`.
+///
+/// # Returns
+///
+/// A `Result` containing a `Vec<T>`, or the first error `E`.
+pub fn collapse_option_results<C, T, E>(collection: C) -> Result<Vec<T>, E>
+where
+    C: IntoIterator<Item = Option<Result<T, E>>>,
+{
+    collection.into_iter().flatten().collect()
+}
+
+/// Extend collections and iterators of `Result`s with aggregate error handling.
+///
+/// Unlike collecting into a `Result<Vec<T>, E>`, which stops at the first error,
+/// these methods consume every item, keeping all the errors in input order.
+///
+/// # Type Parameters
+///
+/// - `T`: The success value type
+/// - `E`: The error type
+pub trait ResultIteratorExt<T, E>: IntoIterator<Item = Result<T, E>> {
+    /// Split the items into successes and errors.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing every `Ok` value and every `Err` value, each in input order.
+    fn partition_result(self) -> (Vec<T>, Vec<E>);
+
+    /// Collect every item, reporting all the errors if there are any.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `Vec<T>` if every item is `Ok`, or a `Vec<E>` of
+    /// all the errors in input order.
+    fn collect_all(self) -> Result<Vec<T>, Vec<E>>;
+}
+
+/// Implementation of `ResultIteratorExt` for all `IntoIterator`s of `Result`s.
+impl<I, T, E> ResultIteratorExt<T, E> for I
+where
+    I: IntoIterator<Item = Result<T, E>>,
+{
+    fn partition_result(self) -> (Vec<T>, Vec<E>) {
+        let mut values = Vec::new();
+        let mut errors = Vec::new();
+
+        for item in self {
+            match item {
+                Ok(x) => values.push(x),
+                Err(e) => errors.push(e),
+            }
+        }
+
+        (values, errors)
+    }
+
+    fn collect_all(self) -> Result<Vec<T>, Vec<E>> {
+        let (values, errors) = self.partition_result();
+
+        if errors.is_empty() {
+            Ok(values)
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// Extend `Result<Option<T>, E>`, as returned by the `Option` methods, with handling of a missing value.
+///
+/// # Type Parameters
+///
+/// - `T`: The success value type
+/// - `E`: The error type
+pub trait ResultOptionExt<T, E> {
+    /// Treat `Ok(None)` as an error, unwrapping `Ok(Some(_))` and passing errors through.
+    ///
+    /// # Parameters
+    ///
+    /// - `on_none`: A function that returns the error `E` for `Ok(None)`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the inner value `T`, or an error `E`.
+    fn require<F>(self, on_none: F) -> Result<T, E>
+    where
+        F: FnOnce() -> E;
+}
+
+/// Implementation of `ResultOptionExt` for `Result<Option<T>, E>`.
+impl<T, E> ResultOptionExt<T, E> for Result<Option<T>, E> {
+    fn require<F>(self, on_none: F) -> Result<T, E>
+    where
+        F: FnOnce() -> E,
+    {
+        self.and_then(|x| x.ok_or_else(on_none))
     }
 }
@@ -1,7 +1,21 @@
 use fallible_map::{
+    FallibleError,
+    FallibleMapCowSliceExt,
+    FallibleMapDerefExt,
     FallibleMapExt,
+    FallibleMapF32Ext,
     FallibleMapIteratorExt,
+    FallibleMapOptionsExt,
+    FallibleMapPairExt,
+    FallibleMapRefExt,
+    MappedError,
+    ResultIteratorExt,
+    ResultOptionExt,
+    TryCollector,
+    collapse_option_results,
+    try_map_all,
 };
+use itertools::process_results;
 
 #[cfg(test)]
 mod tests {
@@ -138,4 +152,2974 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_results_process_results() {
+        let numbers: Vec<i32> = vec![1, 2, 3, 4];
+        let sum: Result<i32, String> = process_results(
+            numbers
+                .into_iter()
+                .try_map(|x| {
+                    if x > 0 {
+                        Ok(x * 10)
+                    } else {
+                        Err(format!("Non-positive value {}", x))
+                    }
+                })
+                .results(),
+            |iter| iter.sum(),
+        );
+
+        assert_eq!(sum, Ok(100));
+
+        let mut calls = 0;
+        let numbers: Vec<i32> = vec![1, -2, 3, 4];
+        let sum: Result<i32, String> = process_results(
+            numbers
+                .into_iter()
+                .try_map(|x| {
+                    calls += 1;
+
+                    if x > 0 {
+                        Ok(x * 10)
+                    } else {
+                        Err(format!("Non-positive value {}", x))
+                    }
+                })
+                .results(),
+            |iter| iter.sum(),
+        );
+
+        assert_eq!(sum, Err("Non-positive value -2".to_string()));
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn test_take_while_ok() {
+        let numbers: Vec<i32> = vec![2, 4, 5, 6];
+        let mut prefix = numbers
+            .into_iter()
+            .try_map(|x| {
+                if x % 2 == 0 {
+                    Ok(x * 2)
+                } else {
+                    Err(format!("Failed to process {}", x))
+                }
+            })
+            .take_while_ok();
+
+        let values: Vec<i32> = prefix.by_ref().collect();
+
+        assert_eq!(values, vec![4, 8]);
+        assert_eq!(prefix.next(), None);
+        assert_eq!(prefix.into_err(), Some("Failed to process 5".to_string()));
+
+        let even_numbers: Vec<i32> = vec![2, 4, 6];
+        let mut prefix = even_numbers
+            .into_iter()
+            .try_map(|x| Ok::<_, String>(x * 2))
+            .take_while_ok();
+        let values: Vec<i32> = prefix.by_ref().collect();
+
+        assert_eq!(values, vec![4, 8, 12]);
+        assert_eq!(prefix.into_err(), None);
+    }
+
+    #[cfg(feature = "threaded")]
+    #[test]
+    fn test_par_try_map_threads() {
+        let numbers: Vec<i32> = (0..1000).collect();
+        let mapped_numbers: Result<Vec<_>, String> =
+            numbers.into_iter().par_try_map_threads(4, |x| Ok(x * 2));
+
+        assert_eq!(mapped_numbers, Ok((0..1000).map(|x| x * 2).collect()));
+
+        let numbers: Vec<i32> = (0..1000).collect();
+        let mapped_numbers: Result<Vec<i32>, String> =
+            numbers.into_iter().par_try_map_threads(4, |x| {
+                if x != 637 {
+                    Ok(x * 2)
+                } else {
+                    Err(format!("Failed to process {}", x))
+                }
+            });
+
+        assert_eq!(mapped_numbers, Err("Failed to process 637".to_string()));
+
+        let numbers: Vec<i32> = (0..1000).collect();
+        let mapped_numbers: Result<Vec<i32>, String> =
+            numbers.into_iter().par_try_map_threads(8, |x| {
+                if x < 500 || x % 2 == 0 {
+                    Ok(x * 2)
+                } else {
+                    Err(format!("Failed to process {}", x))
+                }
+            });
+
+        assert_eq!(mapped_numbers, Err("Failed to process 501".to_string()));
+
+        let empty: Vec<i32> = Vec::new();
+        let mapped_numbers: Result<Vec<i32>, String> =
+            empty.into_iter().par_try_map_threads(0, |x| Ok(x * 2));
+
+        assert_eq!(mapped_numbers, Ok(Vec::new()));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_try_map_timed() {
+        use std::time::Duration;
+
+        let numbers: Vec<i32> = vec![2, 3, 4];
+        let timed: Vec<Result<(i32, Duration), String>> = numbers
+            .into_iter()
+            .try_map_timed(|x| {
+                std::thread::sleep(Duration::from_millis(5));
+
+                if x % 2 == 0 {
+                    Ok(x * 2)
+                } else {
+                    Err(format!("Failed to process {}", x))
+                }
+            })
+            .collect();
+
+        assert_eq!(timed.len(), 3);
+        assert!(matches!(timed[0], Ok((4, elapsed)) if elapsed >= Duration::from_millis(5)));
+        assert_eq!(timed[1], Err("Failed to process 3".to_string()));
+        assert!(matches!(timed[2], Ok((8, elapsed)) if elapsed >= Duration::from_millis(5)));
+    }
+
+    #[test]
+    fn test_try_map_reduce() {
+        let numbers: Vec<&str> = vec!["1", "2", "3"];
+        let sum: Result<i64, String> = numbers.into_iter().try_map_reduce(
+            0,
+            |s| s.parse::<i64>().map_err(|e| e.to_string()),
+            |acc, x| Ok(acc + x),
+        );
+
+        assert_eq!(sum, Ok(6));
+
+        let numbers: Vec<&str> = vec!["1", "x", "3"];
+        let sum: Result<i64, String> = numbers.into_iter().try_map_reduce(
+            0,
+            |s| {
+                s.parse::<i64>()
+                    .map_err(|_| format!("Failed to parse {}", s))
+            },
+            |acc, x| Ok(acc + x),
+        );
+
+        assert_eq!(sum, Err("Failed to parse x".to_string()));
+
+        let mut mapped = 0;
+        let numbers: Vec<u8> = vec![100, 100, 100, 100];
+        let sum: Result<u8, String> = numbers.into_iter().try_map_reduce(
+            0u8,
+            |x| {
+                mapped += 1;
+                Ok(x)
+            },
+            |acc, x| acc.checked_add(x).ok_or_else(|| "Overflow".to_string()),
+        );
+
+        assert_eq!(sum, Err("Overflow".to_string()));
+        assert_eq!(mapped, 3);
+    }
+
+    #[test]
+    fn test_try_position() {
+        let is_even = |x: i32| {
+            if x >= 0 {
+                Ok(x % 2 == 0)
+            } else {
+                Err(format!("Negative value {}", x))
+            }
+        };
+
+        let numbers: Vec<i32> = vec![1, 3, 4, 5];
+        let mut iter = numbers.into_iter();
+
+        assert_eq!(iter.try_position(is_even), Ok(Some(2)));
+        assert_eq!(iter.next(), Some(5));
+
+        let numbers: Vec<i32> = vec![1, 3, 5];
+
+        assert_eq!(numbers.into_iter().try_position(is_even), Ok(None));
+
+        let numbers: Vec<i32> = vec![1, -3, 4];
+
+        assert_eq!(
+            numbers.into_iter().try_position(is_even),
+            Err("Negative value -3".to_string())
+        );
+    }
+
+    #[test]
+    fn test_collect_all() {
+        let numbers: Vec<i32> = vec![1, 2, 3, 4, 5];
+        let collected: Result<Vec<_>, Vec<String>> = numbers
+            .into_iter()
+            .try_map(|x| {
+                if x % 2 == 0 {
+                    Ok(x * 2)
+                } else {
+                    Err(format!("Failed to process {}", x))
+                }
+            })
+            .collect_all();
+
+        assert_eq!(
+            collected,
+            Err(vec![
+                "Failed to process 1".to_string(),
+                "Failed to process 3".to_string(),
+                "Failed to process 5".to_string(),
+            ])
+        );
+
+        let results: Vec<Result<i32, String>> = vec![Ok(1), Ok(2)];
+
+        assert_eq!(results.collect_all(), Ok(vec![1, 2]));
+    }
+
+    #[test]
+    fn test_partition_result() {
+        let results: Vec<Result<i32, String>> =
+            vec![Ok(1), Err("a".to_string()), Ok(3), Err("b".to_string())];
+        let (values, errors) = results.partition_result();
+
+        assert_eq!(values, vec![1, 3]);
+        assert_eq!(errors, vec!["a".to_string(), "b".to_string()]);
+    }
+
+    #[test]
+    fn test_try_expand() {
+        let numbers: Vec<usize> = vec![2, 0, 3];
+        let expanded: Vec<Result<usize, String>> =
+            numbers.into_iter().try_expand(|x| Ok(vec![x; x])).collect();
+
+        assert_eq!(expanded, vec![Ok(2), Ok(2), Ok(3), Ok(3), Ok(3)]);
+
+        let numbers: Vec<usize> = vec![1, 5, 2];
+        let expanded: Vec<Result<usize, String>> = numbers
+            .into_iter()
+            .try_expand(|x| {
+                if x < 4 {
+                    Ok(vec![x; x])
+                } else {
+                    Err(format!("Failed to expand {}", x))
+                }
+            })
+            .collect();
+
+        assert_eq!(
+            expanded,
+            vec![Ok(1), Err("Failed to expand 5".to_string()), Ok(2), Ok(2)]
+        );
+
+        let numbers: Vec<usize> = vec![1, 5, 2];
+        let collected: Result<Vec<usize>, String> = numbers
+            .into_iter()
+            .try_expand(|x| {
+                if x < 4 {
+                    Ok(vec![x; x])
+                } else {
+                    Err(format!("Failed to expand {}", x))
+                }
+            })
+            .collect();
+
+        assert_eq!(collected, Err("Failed to expand 5".to_string()));
+    }
+
+    #[test]
+    fn test_map_ok() {
+        let numbers: Vec<&str> = vec!["1", "x", "3"];
+        let mapped: Vec<Result<String, String>> = numbers
+            .into_iter()
+            .try_map(|s| {
+                s.parse::<i32>()
+                    .map_err(|_| format!("Failed to parse {}", s))
+            })
+            .map_ok(|x| x * 10)
+            .map(|x| x.map(|x| x.to_string()))
+            .collect();
+
+        assert_eq!(
+            mapped,
+            vec![
+                Ok("10".to_string()),
+                Err("Failed to parse x".to_string()),
+                Ok("30".to_string())
+            ]
+        );
+
+        let numbers: Vec<&str> = vec!["1", "2"];
+        let collected: Result<Vec<i32>, String> = numbers
+            .into_iter()
+            .try_map(|s| {
+                s.parse::<i32>()
+                    .map_err(|_| format!("Failed to parse {}", s))
+            })
+            .map_ok(|x| x + 1)
+            .collect();
+
+        assert_eq!(collected, Ok(vec![2, 3]));
+    }
+
+    #[test]
+    fn test_and_then_try() {
+        let numbers: Vec<&str> = vec!["4", "x", "3", "8"];
+        let mapped: Vec<Result<i32, String>> = numbers
+            .into_iter()
+            .try_map(|s| {
+                s.parse::<i32>()
+                    .map_err(|_| format!("Failed to parse {}", s))
+            })
+            .and_then_try(|x| {
+                if x % 2 == 0 {
+                    Ok(x / 2)
+                } else {
+                    Err(format!("Failed to halve {}", x))
+                }
+            })
+            .collect();
+
+        assert_eq!(
+            mapped,
+            vec![
+                Ok(2),
+                Err("Failed to parse x".to_string()),
+                Err("Failed to halve 3".to_string()),
+                Ok(4),
+            ]
+        );
+
+        let numbers: Vec<&str> = vec!["4", "3", "8"];
+        let collected: Result<Vec<i32>, String> = numbers
+            .into_iter()
+            .try_map(|s| {
+                s.parse::<i32>()
+                    .map_err(|_| format!("Failed to parse {}", s))
+            })
+            .and_then_try(|x| {
+                if x % 2 == 0 {
+                    Ok(x / 2)
+                } else {
+                    Err(format!("Failed to halve {}", x))
+                }
+            })
+            .collect();
+
+        assert_eq!(collected, Err("Failed to halve 3".to_string()));
+    }
+
+    #[test]
+    fn test_try_collect_boxed() {
+        let numbers: Vec<i32> = vec![2, 4, 6];
+        let boxed: Result<Box<[i32]>, String> =
+            numbers.into_iter().try_collect_boxed(|x| Ok(x * 2));
+
+        assert_eq!(boxed.as_deref(), Ok(&[4, 8, 12][..]));
+
+        let mut calls = 0;
+        let numbers: Vec<i32> = vec![2, 3, 4];
+        let boxed: Result<Box<[i32]>, String> = numbers.into_iter().try_collect_boxed(|x| {
+            calls += 1;
+
+            if x % 2 == 0 {
+                Ok(x * 2)
+            } else {
+                Err(format!("Failed to process {}", x))
+            }
+        });
+
+        assert_eq!(boxed, Err("Failed to process 3".to_string()));
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn test_try_map_try_from() {
+        let some_number: Option<i64> = Some(200);
+
+        assert_eq!(some_number.try_map_try_from::<u8>(), Ok(Some(200u8)));
+
+        let some_number: Option<i64> = Some(300);
+
+        assert!(some_number.try_map_try_from::<u8>().is_err());
+
+        let none_number: Option<i64> = None;
+
+        assert_eq!(none_number.try_map_try_from::<u8>(), Ok(None));
+    }
+
+    #[test]
+    fn test_collect_result() -> Result<(), String> {
+        fn parse_all(input: &[&str]) -> Result<Vec<i32>, String> {
+            let numbers = input
+                .iter()
+                .try_map(|s| {
+                    s.parse::<i32>()
+                        .map_err(|_| format!("Failed to parse {}", s))
+                })
+                .collect_result()?;
+
+            Ok(numbers)
+        }
+
+        let numbers = parse_all(&["1", "2", "3"])?;
+
+        assert_eq!(numbers, vec![1, 2, 3]);
+        assert_eq!(
+            parse_all(&["1", "x", "3"]),
+            Err("Failed to parse x".to_string())
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_try_map_collect_unique_errors() {
+        let inputs: Vec<&str> = vec!["1", "", "x", "", "2", "x", "-"];
+        let collected: Result<Vec<i32>, Vec<String>> =
+            inputs.into_iter().try_map_collect_unique_errors(|s| {
+                if s.is_empty() {
+                    Err("Empty value".to_string())
+                } else {
+                    s.parse::<i32>().map_err(|_| format!("Invalid value {}", s))
+                }
+            });
+
+        assert_eq!(
+            collected,
+            Err(vec![
+                "Empty value".to_string(),
+                "Invalid value x".to_string(),
+                "Invalid value -".to_string(),
+            ])
+        );
+
+        let inputs: Vec<&str> = vec!["1", "2"];
+        let collected: Result<Vec<i32>, Vec<String>> =
+            inputs.into_iter().try_map_collect_unique_errors(|s| {
+                s.parse::<i32>().map_err(|_| format!("Invalid value {}", s))
+            });
+
+        assert_eq!(collected, Ok(vec![1, 2]));
+    }
+
+    #[test]
+    fn test_try_map_located() {
+        let inputs: Vec<&str> = vec!["1", "2", "x", "4"];
+        let collected: Result<Vec<i32>, MappedError<std::num::ParseIntError>> = inputs
+            .into_iter()
+            .try_map_located(|s| s.parse::<i32>())
+            .collect();
+        let error = collected.unwrap_err();
+
+        assert_eq!(error.index, 2);
+        assert_eq!(
+            error.to_string(),
+            format!("failed to map item 2: {}", "x".parse::<i32>().unwrap_err())
+        );
+
+        #[cfg(feature = "std")]
+        assert_eq!(
+            std::error::Error::source(&error).map(|e| e.to_string()),
+            Some(error.clone().into_inner().to_string())
+        );
+
+        let inputs: Vec<&str> = vec!["x", "1", "y"];
+        let indices: Vec<usize> = inputs
+            .into_iter()
+            .try_map_located(|s| s.parse::<i32>())
+            .filter_map(|x| x.err())
+            .map(|e| e.index)
+            .collect();
+
+        assert_eq!(indices, vec![0, 2]);
+    }
+
+    #[test]
+    fn test_try_map_report() {
+        let rows: Vec<&str> = vec!["1", "x", "3", "", "5"];
+        let (values, errors) = rows
+            .into_iter()
+            .try_map_report(|s| s.parse::<i32>().map_err(|_| format!("Invalid row {:?}", s)));
+
+        assert_eq!(values, vec![1, 3, 5]);
+        assert_eq!(
+            errors,
+            vec![
+                (1, "Invalid row \"x\"".to_string()),
+                (3, "Invalid row \"\"".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_try_validate_option() {
+        let validate = |num: &i32| {
+            if *num >= 0 {
+                Ok(())
+            } else {
+                Err("Negative number".to_string())
+            }
+        };
+
+        let some_number: Option<i32> = Some(2);
+
+        assert_eq!(some_number.try_validate(validate), Ok(Some(2)));
+
+        let some_number: Option<i32> = Some(-2);
+
+        assert_eq!(
+            some_number.try_validate(validate),
+            Err("Negative number".to_string())
+        );
+
+        let none_number: Option<i32> = None;
+
+        assert_eq!(none_number.try_validate(validate), Ok(None));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_try_map_lines() {
+        use std::io::{BufRead, Cursor, Error, ErrorKind};
+
+        #[derive(Debug, PartialEq)]
+        enum LineError {
+            Io(ErrorKind),
+            Parse(String),
+        }
+
+        impl From<Error> for LineError {
+            fn from(e: Error) -> Self {
+                LineError::Io(e.kind())
+            }
+        }
+
+        let parse = |line: String| {
+            line.trim()
+                .parse::<i32>()
+                .map_err(|_| LineError::Parse(line))
+        };
+
+        let cursor = Cursor::new("1\n2\nx\n4\n");
+        let parsed: Vec<Result<i32, LineError>> = cursor.lines().try_map_lines(parse).collect();
+
+        assert_eq!(
+            parsed,
+            vec![Ok(1), Ok(2), Err(LineError::Parse("x".to_string())), Ok(4)]
+        );
+
+        let cursor = Cursor::new(b"1\n\xff\n3\n".to_vec());
+        let parsed: Result<Vec<i32>, LineError> = cursor.lines().try_map_lines(parse).collect();
+
+        assert_eq!(parsed, Err(LineError::Io(ErrorKind::InvalidData)));
+    }
+
+    #[test]
+    fn test_try_map_control() {
+        use std::ops::ControlFlow;
+
+        let commands: Vec<&str> = vec!["1", "x", "2", "quit", "3"];
+        let mut iter = commands.into_iter().try_map_control(|s| {
+            if s == "quit" {
+                Ok(ControlFlow::Break("quit"))
+            } else {
+                s.parse::<i32>()
+                    .map(ControlFlow::Continue)
+                    .map_err(|_| format!("Invalid command {}", s))
+            }
+        });
+
+        let outputs: Vec<Result<i32, String>> = iter.by_ref().collect();
+
+        assert_eq!(
+            outputs,
+            vec![Ok(1), Err("Invalid command x".to_string()), Ok(2)]
+        );
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.break_value(), Some(&"quit"));
+        assert_eq!(iter.into_break(), Some("quit"));
+
+        let commands: Vec<&str> = vec!["1", "2"];
+        let mut iter = commands.into_iter().try_map_control(|s| {
+            s.parse::<i32>()
+                .map(ControlFlow::<(), _>::Continue)
+                .map_err(|_| format!("Invalid command {}", s))
+        });
+
+        assert_eq!(iter.by_ref().collect::<Result<Vec<_>, _>>(), Ok(vec![1, 2]));
+        assert_eq!(iter.into_break(), None);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_try_map_async_boxed() {
+        let some_number: Option<i32> = Some(2);
+        let result: Result<Option<i32>, String> = some_number
+            .try_map_async_boxed(|num| {
+                Box::pin(async move {
+                    if num % 2 == 0 {
+                        Ok(num * 2)
+                    } else {
+                        Err("Odd number".to_string())
+                    }
+                })
+            })
+            .await;
+
+        assert_eq!(result, Ok(Some(4)));
+
+        let some_number: Option<i32> = Some(3);
+        let result: Result<Option<i32>, String> = some_number
+            .try_map_async_boxed(|_| Box::pin(async { Err("Odd number".to_string()) }))
+            .await;
+
+        assert_eq!(result, Err("Odd number".to_string()));
+
+        let none_number: Option<i32> = None;
+        let result: Result<Option<i32>, String> = none_number
+            .try_map_async_boxed(|num| Box::pin(async move { Ok(num * 2) }))
+            .await;
+
+        assert_eq!(result, Ok(None));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_try_map_async_boxed_spawn() {
+        let future = Some(2)
+            .try_map_async_boxed(|num: i32| Box::pin(async move { Ok::<_, String>(num * 2) }));
+
+        assert_eq!(tokio::spawn(future).await.unwrap(), Ok(Some(4)));
+    }
+
+    #[test]
+    fn test_try_map_to_option() {
+        let some_text: Option<&str> = Some("42");
+
+        assert_eq!(some_text.try_map_to::<u64, _>(str::parse), Ok(Some(42)));
+
+        let some_text: Option<&str> = Some("-1");
+
+        assert!(some_text.try_map_to::<u64, _>(|s| s.parse()).is_err());
+
+        let none_text: Option<&str> = None;
+
+        assert_eq!(none_text.try_map_to::<i8, _>(str::parse), Ok(None));
+    }
+
+    #[test]
+    fn test_try_map_borrow_option() {
+        fn first_word(text: &Option<String>) -> Result<Option<&str>, &str> {
+            text.try_map_borrow(|s| match s.split_whitespace().next() {
+                Some(word) if word.chars().all(char::is_alphabetic) => Ok(word),
+                Some(word) => Err(word),
+                None => Err(s.as_str()),
+            })
+        }
+
+        let some_text: Option<String> = Some("hello world".to_string());
+
+        assert_eq!(first_word(&some_text), Ok(Some("hello")));
+
+        let some_text: Option<String> = Some("h3llo world".to_string());
+
+        assert_eq!(first_word(&some_text), Err("h3llo"));
+
+        let none_text: Option<String> = None;
+
+        assert_eq!(first_word(&none_text), Ok(None));
+    }
+
+    #[test]
+    fn test_try_map_with_input() {
+        let inputs: Vec<String> = vec!["1".to_string(), "x".to_string(), "3".to_string()];
+        let pairs: Vec<Result<(String, i32), String>> = inputs
+            .into_iter()
+            .try_map_with_input(|s| {
+                s.parse::<i32>()
+                    .map_err(|_| format!("Failed to parse {}", s))
+            })
+            .collect();
+
+        assert_eq!(
+            pairs,
+            vec![
+                Ok(("1".to_string(), 1)),
+                Err("Failed to parse x".to_string()),
+                Ok(("3".to_string(), 3)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_try_for_each_indexed() {
+        let mut visited = Vec::new();
+        let inputs: Vec<&str> = vec!["a", "b", "", "d"];
+        let result: Result<(), String> = inputs.into_iter().try_for_each_indexed(|index, s| {
+            if s.is_empty() {
+                return Err(format!("Empty value at index {}", index));
+            }
+
+            visited.push((index, s));
+
+            Ok(())
+        });
+
+        assert_eq!(result, Err("Empty value at index 2".to_string()));
+        assert_eq!(visited, vec![(0, "a"), (1, "b")]);
+
+        let inputs: Vec<&str> = vec!["a", "b"];
+        let result: Result<(), String> = inputs.into_iter().try_for_each_indexed(|_, _| Ok(()));
+
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn test_try_drain() {
+        let mut removed = Vec::new();
+        let paths: Vec<&str> = vec!["a.tmp", "b.tmp", "locked.tmp", "c.tmp"];
+        let result: Result<(), String> = paths.into_iter().try_drain(|path| {
+            if path.starts_with("locked") {
+                return Err(format!("Failed to remove {}", path));
+            }
+
+            removed.push(path);
+
+            Ok(())
+        });
+
+        assert_eq!(result, Err("Failed to remove locked.tmp".to_string()));
+        assert_eq!(removed, vec!["a.tmp", "b.tmp"]);
+    }
+
+    #[test]
+    fn test_try_map_options() {
+        let parse = |s: &str| {
+            s.parse::<i32>()
+                .map_err(|_| format!("Failed to parse {}", s))
+        };
+
+        let values: Vec<Option<&str>> = vec![Some("1"), None, Some("3")];
+
+        assert_eq!(
+            values.try_map_options(parse),
+            Ok(vec![Some(1), None, Some(3)])
+        );
+
+        let values: Vec<Option<&str>> = vec![Some("1"), None, Some("x"), Some("y")];
+
+        assert_eq!(
+            values.try_map_options(parse),
+            Err("Failed to parse x".to_string())
+        );
+    }
+
+    #[test]
+    fn test_try_reduce() {
+        let checked_add = |a: u8, b: u8| a.checked_add(b).ok_or_else(|| "Overflow".to_string());
+
+        let numbers: Vec<u8> = vec![1, 2, 3];
+
+        assert_eq!(numbers.into_iter().try_reduce(checked_add), Ok(6));
+
+        let numbers: Vec<u8> = vec![100, 100, 100];
+
+        assert_eq!(
+            numbers.into_iter().try_reduce(checked_add),
+            Err(FallibleError::Mapper("Overflow".to_string()))
+        );
+
+        let empty: Vec<u8> = Vec::new();
+
+        assert_eq!(
+            empty.into_iter().try_reduce(checked_add),
+            Err(FallibleError::Empty)
+        );
+    }
+
+    #[test]
+    fn test_try_first() {
+        let parse = |s: &str| {
+            s.parse::<i32>()
+                .map_err(|_| format!("Failed to parse {}", s))
+        };
+
+        let inputs: Vec<&str> = vec!["1", "x"];
+
+        assert_eq!(inputs.into_iter().try_first(parse), Ok(1));
+
+        let inputs: Vec<&str> = vec!["x", "1"];
+        let error = inputs.into_iter().try_first(parse).unwrap_err();
+
+        assert_eq!(
+            error,
+            FallibleError::Mapper("Failed to parse x".to_string())
+        );
+        assert_eq!(error.to_string(), "Failed to parse x");
+
+        let empty: Vec<&str> = Vec::new();
+        let error = empty.into_iter().try_first(parse).unwrap_err();
+
+        assert_eq!(error, FallibleError::Empty);
+        assert_eq!(error.to_string(), "the iterator yielded no elements");
+    }
+
+    #[test]
+    fn test_try_for_each_ref() {
+        let mut seen = Vec::new();
+        let numbers: Vec<i32> = vec![1, 2, -3, 4, -5, 6];
+        let mut iter = numbers.into_iter();
+
+        let check = |x: i32, seen: &mut Vec<i32>| {
+            if x < 0 {
+                return Err(format!("Negative value {}", x));
+            }
+
+            seen.push(x);
+
+            Ok(())
+        };
+
+        assert_eq!(
+            iter.try_for_each_ref(|x| check(x, &mut seen)),
+            Err("Negative value -3".to_string())
+        );
+        assert_eq!(seen, vec![1, 2]);
+
+        assert_eq!(
+            iter.try_for_each_ref(|x| check(x, &mut seen)),
+            Err("Negative value -5".to_string())
+        );
+        assert_eq!(seen, vec![1, 2, 4]);
+
+        assert_eq!(iter.try_for_each_ref(|x| check(x, &mut seen)), Ok(()));
+        assert_eq!(seen, vec![1, 2, 4, 6]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_try_map_throttled() {
+        use std::time::{Duration, Instant};
+
+        let interval = Duration::from_millis(20);
+        let mut calls = Vec::new();
+        let numbers: Vec<i32> = vec![2, 3, 4];
+        let mapped: Vec<Result<i32, String>> = numbers
+            .into_iter()
+            .try_map_throttled(interval, |x| {
+                calls.push(Instant::now());
+
+                if x % 2 == 0 {
+                    Ok(x * 2)
+                } else {
+                    Err(format!("Failed to process {}", x))
+                }
+            })
+            .collect();
+
+        assert_eq!(
+            mapped,
+            vec![Ok(4), Err("Failed to process 3".to_string()), Ok(8)]
+        );
+        assert_eq!(calls.len(), 3);
+
+        for pair in calls.windows(2) {
+            assert!(pair[1].duration_since(pair[0]) + Duration::from_millis(1) >= interval);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_try_map_deadline() {
+        use std::time::{Duration, Instant};
+
+        let deadline = Instant::now() + Duration::from_millis(30);
+        let numbers: Vec<i32> = (0..10).collect();
+        let mapped: Result<Vec<i32>, String> = numbers
+            .into_iter()
+            .try_map_deadline(deadline, |x| {
+                std::thread::sleep(Duration::from_millis(20));
+
+                Ok(x * 2)
+            })
+            .collect();
+
+        let mapped = mapped.unwrap();
+
+        assert!(!mapped.is_empty() && mapped.len() < 10);
+        assert_eq!(
+            mapped,
+            (0..mapped.len() as i32).map(|x| x * 2).collect::<Vec<_>>()
+        );
+        assert!(Instant::now() >= deadline);
+
+        let numbers: Vec<i32> = vec![1, 2, 3];
+        let mut calls = 0;
+        let mapped: Vec<Result<i32, String>> = numbers
+            .into_iter()
+            .try_map_deadline(Instant::now(), |x| {
+                calls += 1;
+
+                Ok(x)
+            })
+            .collect();
+
+        assert!(mapped.is_empty());
+        assert_eq!(calls, 0);
+    }
+
+    #[test]
+    fn test_try_map_cow_slice() {
+        use std::borrow::Cow;
+        use std::convert::TryFrom;
+
+        let narrow = |x: Cow<'_, i64>| u8::try_from(*x).map_err(|_| format!("Out of range {}", x));
+
+        let numbers: Vec<i64> = vec![1, 2];
+        let borrowed: Cow<'_, [i64]> = Cow::Borrowed(&numbers);
+        let kinds: Vec<bool> = borrowed
+            .clone()
+            .try_map_cow_slice(|x| Ok::<_, String>(matches!(x, Cow::Borrowed(_))))
+            .unwrap();
+
+        assert_eq!(kinds, vec![true, true]);
+        assert_eq!(borrowed.try_map_cow_slice(narrow), Ok(vec![1, 2]));
+
+        let owned: Cow<'_, [i64]> = Cow::Owned(vec![3, 4]);
+        let kinds: Vec<bool> = owned
+            .clone()
+            .try_map_cow_slice(|x| Ok::<_, String>(matches!(x, Cow::Owned(_))))
+            .unwrap();
+
+        assert_eq!(kinds, vec![true, true]);
+        assert_eq!(owned.try_map_cow_slice(narrow), Ok(vec![3, 4]));
+
+        let numbers: Vec<i64> = vec![1, 300, -1];
+
+        assert_eq!(
+            Cow::Borrowed(&numbers[..]).try_map_cow_slice(narrow),
+            Err("Out of range 300".to_string())
+        );
+        assert_eq!(
+            Cow::<[i64]>::Owned(numbers).try_map_cow_slice(narrow),
+            Err("Out of range 300".to_string())
+        );
+    }
+
+    #[test]
+    fn test_try_collect_with_first_error() {
+        let mut calls = 0;
+        let numbers: Vec<i32> = vec![2, 4, 5, 6, 7];
+        let (values, error) = numbers.into_iter().try_collect_with_first_error(|x| {
+            calls += 1;
+
+            if x % 2 == 0 {
+                Ok(x * 2)
+            } else {
+                Err(format!("Failed to process {}", x))
+            }
+        });
+
+        assert_eq!(values, vec![4, 8]);
+        assert_eq!(error, Some("Failed to process 5".to_string()));
+        assert_eq!(calls, 3);
+
+        let numbers: Vec<i32> = vec![2, 4];
+        let (values, error) = numbers
+            .into_iter()
+            .try_collect_with_first_error(|x| Ok::<_, String>(x * 2));
+
+        assert_eq!(values, vec![4, 8]);
+        assert_eq!(error, None);
+    }
+
+    #[test]
+    fn test_try_get_or_insert_option() {
+        let mut cache: Option<String> = None;
+
+        assert_eq!(
+            cache.try_get_or_insert(|| Err::<String, _>("Unavailable".to_string())),
+            Err("Unavailable".to_string())
+        );
+        assert_eq!(cache, None);
+
+        assert_eq!(
+            cache.try_get_or_insert(|| Ok::<_, String>("value".to_string())),
+            Ok("value".to_string())
+        );
+        assert_eq!(cache, Some("value".to_string()));
+
+        assert_eq!(
+            cache.try_get_or_insert(|| Err::<String, _>("Unavailable".to_string())),
+            Ok("value".to_string())
+        );
+        assert_eq!(cache, Some("value".to_string()));
+    }
+
+    #[test]
+    fn test_try_intersperse_with() {
+        let words: Vec<&str> = vec!["a", "b", "c"];
+        let joined: Result<String, String> = words
+            .into_iter()
+            .try_map(|s| Ok(s.to_string()))
+            .try_intersperse_with(|| Ok(", ".to_string()))
+            .collect();
+
+        assert_eq!(joined, Ok("a, b, c".to_string()));
+
+        let words: Vec<&str> = vec!["a", "", "c"];
+        let items: Vec<Result<String, String>> = words
+            .into_iter()
+            .try_map(|s| {
+                if s.is_empty() {
+                    Err("Empty word".to_string())
+                } else {
+                    Ok(s.to_string())
+                }
+            })
+            .try_intersperse_with(|| Ok("-".to_string()))
+            .collect();
+
+        assert_eq!(
+            items,
+            vec![
+                Ok("a".to_string()),
+                Ok("-".to_string()),
+                Err("Empty word".to_string()),
+                Ok("-".to_string()),
+                Ok("c".to_string()),
+            ]
+        );
+
+        let mut separators = 0;
+        let words: Vec<&str> = vec!["a", "b", "c", "d"];
+        let items: Vec<Result<String, String>> = words
+            .into_iter()
+            .try_map(|s| Ok(s.to_string()))
+            .try_intersperse_with(|| {
+                separators += 1;
+
+                if separators < 2 {
+                    Ok("+".to_string())
+                } else {
+                    Err("Out of separators".to_string())
+                }
+            })
+            .collect();
+
+        assert_eq!(
+            items,
+            vec![
+                Ok("a".to_string()),
+                Ok("+".to_string()),
+                Ok("b".to_string()),
+                Err("Out of separators".to_string()),
+            ]
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_try_map_with_timeout() {
+        use fallible_map::TimeoutError;
+        use std::time::Duration;
+
+        let delays: Vec<u64> = vec![0, 500, 0, 1];
+        let mapped: Vec<Result<u64, TimeoutError<String>>> = delays
+            .into_iter()
+            .try_map_with_timeout(Duration::from_millis(100), |delay| {
+                std::thread::sleep(Duration::from_millis(delay));
+
+                if delay != 1 {
+                    Ok(delay)
+                } else {
+                    Err(format!("Failed to process {}", delay))
+                }
+            })
+            .collect();
+
+        assert_eq!(
+            mapped,
+            vec![
+                Ok(0),
+                Err(TimeoutError::Timeout),
+                Ok(0),
+                Err(TimeoutError::Mapper("Failed to process 1".to_string())),
+            ]
+        );
+        assert_eq!(
+            TimeoutError::<String>::Timeout.to_string(),
+            "the mapping function timed out"
+        );
+    }
+
+    #[test]
+    fn test_try_map_deref_option() {
+        let text: String = "42".to_string();
+        let some_ref: Option<&String> = Some(&text);
+        let result: Result<Option<usize>, String> = some_ref.try_map(|s: &String| Ok(s.len()));
+
+        assert_eq!(result, Ok(Some(2)));
+
+        let result: Result<Option<&str>, String> = some_ref.try_map_deref(|s: &str| {
+            if s.chars().all(|c| c.is_ascii_digit()) {
+                Ok(s)
+            } else {
+                Err(format!("Not a number {}", s))
+            }
+        });
+
+        assert_eq!(result, Ok(Some("42")));
+
+        let text: String = "4x".to_string();
+        let some_ref: Option<&String> = Some(&text);
+
+        assert_eq!(
+            some_ref
+                .try_map_deref(str::parse::<i32>)
+                .map_err(|e| e.to_string()),
+            Err("invalid digit found in string".to_string())
+        );
+
+        let none_ref: Option<&String> = None;
+
+        assert_eq!(none_ref.try_map_deref(str::parse::<i32>), Ok(None));
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_into_stream() {
+        use futures::executor::block_on;
+        use futures::TryStreamExt;
+
+        let numbers: Vec<i32> = vec![2, 4, 6];
+        let stream = numbers
+            .into_iter()
+            .try_map(|x| Ok::<_, String>(x * 2))
+            .into_stream();
+
+        assert_eq!(block_on(stream.try_collect::<Vec<_>>()), Ok(vec![4, 8, 12]));
+
+        let numbers: Vec<i32> = vec![2, 3, 4];
+        let stream = numbers
+            .into_iter()
+            .try_map(|x| {
+                if x % 2 == 0 {
+                    Ok(x * 2)
+                } else {
+                    Err(format!("Failed to process {}", x))
+                }
+            })
+            .into_stream();
+
+        assert_eq!(
+            block_on(stream.try_collect::<Vec<_>>()),
+            Err("Failed to process 3".to_string())
+        );
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_try_map_spawn_blocking() {
+        #[derive(Debug, PartialEq)]
+        enum TaskError {
+            Odd(i32),
+            Join,
+        }
+
+        impl From<tokio::task::JoinError> for TaskError {
+            fn from(_: tokio::task::JoinError) -> Self {
+                TaskError::Join
+            }
+        }
+
+        let numbers: Vec<i32> = vec![2, 4, 6];
+        let mapped = numbers
+            .into_iter()
+            .try_map_spawn_blocking(|x| Ok::<_, TaskError>(x * 2))
+            .await;
+
+        assert_eq!(mapped, Ok(vec![4, 8, 12]));
+
+        let numbers: Vec<i32> = vec![2, 3, 4];
+        let mapped: Result<Vec<i32>, TaskError> = numbers
+            .into_iter()
+            .try_map_spawn_blocking(|x| {
+                if x % 2 == 0 {
+                    Ok(x * 2)
+                } else {
+                    Err(TaskError::Odd(x))
+                }
+            })
+            .await;
+
+        assert_eq!(mapped, Err(TaskError::Odd(3)));
+
+        let numbers: Vec<i32> = vec![1];
+        let mapped: Result<Vec<i32>, TaskError> = numbers
+            .into_iter()
+            .try_map_spawn_blocking(|_| panic!("Worker failure"))
+            .await;
+
+        assert_eq!(mapped, Err(TaskError::Join));
+    }
+
+    #[test]
+    fn test_try_map_warn() {
+        let parse = |s: &str| {
+            let trimmed = s.trim();
+            let warning = if trimmed.len() != s.len() {
+                Some(format!("Trimmed whitespace around {:?}", trimmed))
+            } else {
+                None
+            };
+
+            trimmed
+                .parse::<i32>()
+                .map(|x| (x, warning))
+                .map_err(|_| format!("Failed to parse {:?}", s))
+        };
+
+        let inputs: Vec<&str> = vec!["1", " 2", "3 "];
+        let (values, warnings) = inputs.into_iter().try_map_warn(parse);
+
+        assert_eq!(values, Ok(vec![1, 2, 3]));
+        assert_eq!(
+            warnings,
+            vec![
+                "Trimmed whitespace around \"2\"".to_string(),
+                "Trimmed whitespace around \"3\"".to_string()
+            ]
+        );
+
+        let inputs: Vec<&str> = vec![" 1", "x", "3 "];
+        let (values, warnings) = inputs.into_iter().try_map_warn(parse);
+
+        assert_eq!(values, Err("Failed to parse \"x\"".to_string()));
+        assert_eq!(
+            warnings,
+            vec!["Trimmed whitespace around \"1\"".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_try_map_nested() {
+        let rows: Vec<&str> = vec!["1,2", "3", "a,4", "5,6,7"];
+        let nested: Vec<Result<Vec<Result<i32, String>>, String>> = rows
+            .into_iter()
+            .try_map_nested(|row| {
+                if row.starts_with(char::is_alphabetic) {
+                    return Err(format!("Invalid row {}", row));
+                }
+
+                Ok(row
+                    .split(',')
+                    .try_map(|s| s.parse::<i32>().map_err(|_| format!("Invalid cell {}", s))))
+            })
+            .map(|inner| inner.map(Iterator::collect))
+            .collect();
+
+        assert_eq!(
+            nested,
+            vec![
+                Ok(vec![Ok(1), Ok(2)]),
+                Ok(vec![Ok(3)]),
+                Err("Invalid row a,4".to_string()),
+                Ok(vec![Ok(5), Ok(6), Ok(7)]),
+            ]
+        );
+
+        let calls = std::cell::Cell::new(0);
+        let groups: Vec<Vec<i32>> = vec![vec![1, 2], vec![3]];
+        let mut nested = groups.into_iter().try_map_nested(|group| {
+            Ok::<_, String>(group.into_iter().map(|x| {
+                calls.set(calls.get() + 1);
+                x * 10
+            }))
+        });
+
+        let mut first = nested.next().unwrap().unwrap();
+
+        assert_eq!(first.next(), Some(10));
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn test_try_map_required_option() {
+        use std::num::NonZeroU32;
+
+        let some_number: Option<u32> = Some(5);
+        let result: Result<Option<NonZeroU32>, String> =
+            some_number.try_map_required(NonZeroU32::new, || "Zero value".to_string());
+
+        assert_eq!(result, Ok(NonZeroU32::new(5)));
+
+        let some_number: Option<u32> = Some(0);
+        let result: Result<Option<NonZeroU32>, String> =
+            some_number.try_map_required(NonZeroU32::new, || "Zero value".to_string());
+
+        assert_eq!(result, Err("Zero value".to_string()));
+
+        let none_number: Option<u32> = None;
+        let result: Result<Option<NonZeroU32>, String> =
+            none_number.try_map_required(NonZeroU32::new, || "Zero value".to_string());
+
+        assert_eq!(result, Ok(None));
+    }
+
+    #[test]
+    fn test_try_collector() -> Result<(), String> {
+        let parse = |s: &str| {
+            s.parse::<i32>()
+                .map_err(|_| format!("Failed to parse {}", s))
+        };
+
+        let values = TryCollector::new()
+            .extend_try(vec!["1", "2"], parse)?
+            .extend_try([3, 4], |x| Ok(x * 10))?
+            .finish();
+
+        assert_eq!(values, vec![1, 2, 30, 40]);
+
+        let mut calls = 0;
+        let result = TryCollector::with_capacity(4)
+            .extend_try(vec!["1", "2"], parse)
+            .and_then(|collector| {
+                collector.extend_try(vec!["3", "x", "5"], |s| {
+                    calls += 1;
+                    parse(s)
+                })
+            })
+            .map(TryCollector::finish);
+
+        assert_eq!(result, Err("Failed to parse x".to_string()));
+        assert_eq!(calls, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_map_flat_option() {
+        let load = |key: &str| -> Result<Result<i32, String>, String> {
+            match key {
+                "missing" => Err("Failed to read missing".to_string()),
+                _ => Ok(key
+                    .parse::<i32>()
+                    .map_err(|_| format!("Failed to parse {}", key))),
+            }
+        };
+
+        assert_eq!(Some("42").try_map_flat(load), Ok(Some(42)));
+        assert_eq!(
+            Some("x").try_map_flat(load),
+            Err("Failed to parse x".to_string())
+        );
+        assert_eq!(
+            Some("missing").try_map_flat(load),
+            Err("Failed to read missing".to_string())
+        );
+        assert_eq!(None.try_map_flat(load), Ok(None));
+    }
+
+    #[test]
+    fn test_try_map_all() {
+        use std::collections::HashSet;
+
+        let halve = |x: i32| {
+            if x % 2 == 0 {
+                Ok(x / 2)
+            } else {
+                Err(format!("Failed to halve {}", x))
+            }
+        };
+
+        assert_eq!(try_map_all(vec![2, 4, 6], halve), Ok(vec![1, 2, 3]));
+        assert_eq!(
+            try_map_all([2, 3, 4], halve),
+            Err("Failed to halve 3".to_string())
+        );
+
+        let set: HashSet<i32> = vec![2, 4, 6].into_iter().collect();
+        let mut halves = try_map_all(set, halve).unwrap();
+
+        halves.sort_unstable();
+
+        assert_eq!(halves, vec![1, 2, 3]);
+
+        let set: HashSet<i32> = vec![2, 5].into_iter().collect();
+
+        assert_eq!(
+            try_map_all(&set, |x| halve(*x)),
+            Err("Failed to halve 5".to_string())
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_try_map_values_in_place() {
+        use fallible_map::collections::FallibleHashMapExt;
+        use std::collections::HashMap;
+
+        let double = |x: &mut i32| {
+            if *x < 0 {
+                return Err(format!("Negative value {}", x));
+            }
+
+            *x *= 2;
+
+            Ok(())
+        };
+
+        let mut map: HashMap<&str, i32> = vec![("a", 1), ("b", 2), ("c", 3)].into_iter().collect();
+
+        assert_eq!(map.try_map_values_in_place(double), Ok(()));
+        assert_eq!(
+            map,
+            vec![("a", 2), ("b", 4), ("c", 6)].into_iter().collect()
+        );
+
+        let mut map: HashMap<&str, i32> = vec![("a", 1), ("b", -2), ("c", 3)].into_iter().collect();
+
+        assert_eq!(
+            map.try_map_values_in_place(double),
+            Err("Negative value -2".to_string())
+        );
+        assert_eq!(map["b"], -2);
+        assert!(map["a"] == 1 || map["a"] == 2);
+        assert!(map["c"] == 3 || map["c"] == 6);
+    }
+
+    #[test]
+    fn test_try_retain_vec() {
+        use fallible_map::collections::FallibleVecExt;
+
+        let is_even = |x: &i32| {
+            if *x >= 0 {
+                Ok(x % 2 == 0)
+            } else {
+                Err(format!("Negative value {}", x))
+            }
+        };
+
+        let mut numbers: Vec<i32> = vec![1, 2, 3, 4, 5, 6];
+
+        assert_eq!(numbers.try_retain(is_even), Ok(()));
+        assert_eq!(numbers, vec![2, 4, 6]);
+
+        let mut numbers: Vec<i32> = vec![1, 2, 3, 4, -5, 6, 7];
+
+        assert_eq!(
+            numbers.try_retain(is_even),
+            Err("Negative value -5".to_string())
+        );
+        assert_eq!(numbers, vec![2, 4, -5, 6, 7]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_try_retain_hash_map() {
+        use fallible_map::collections::FallibleHashMapExt;
+        use std::collections::HashMap;
+
+        let mut map: HashMap<&str, i32> = vec![("a", 1), ("b", 2), ("c", 3), ("d", 4)]
+            .into_iter()
+            .collect();
+        let result: Result<(), String> = map.try_retain(|_, v| {
+            *v *= 10;
+            Ok(*v > 20)
+        });
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(map, vec![("c", 30), ("d", 40)].into_iter().collect());
+
+        let mut map: HashMap<&str, i32> = vec![("a", 1), ("b", -2), ("c", 3), ("d", 4)]
+            .into_iter()
+            .collect();
+        let result = map.try_retain(|k, v| {
+            if *v < 0 {
+                Err(format!("Negative value for {}", k))
+            } else {
+                Ok(*v % 2 == 0)
+            }
+        });
+
+        assert_eq!(result, Err("Negative value for b".to_string()));
+        assert_eq!(map.get("b"), Some(&-2));
+        assert_eq!(map.get("d"), Some(&4));
+        assert!(map.len() >= 2 && map.len() <= 4);
+    }
+
+    #[test]
+    fn test_try_merge_map() {
+        let left: Vec<(i32, &str)> = vec![(1, "l"), (3, "l"), (5, "l")];
+        let right: Vec<(i32, &str)> = vec![(2, "r"), (3, "r"), (6, "r"), (7, "r")];
+        let merged: Result<Vec<String>, String> = left.into_iter().try_merge_map(
+            right,
+            |a, b| a.0.cmp(&b.0),
+            |(x, side)| Ok(format!("{}{}", x, side)),
+        );
+
+        assert_eq!(
+            merged,
+            Ok(vec!["1l", "2r", "3l", "3r", "5l", "6r", "7r"]
+                .into_iter()
+                .map(String::from)
+                .collect())
+        );
+
+        let mut calls = 0;
+        let left: Vec<i32> = vec![1, 4, 8];
+        let right: Vec<i32> = vec![2, 3, 9];
+        let merged: Result<Vec<i32>, String> = left.into_iter().try_merge_map(
+            right,
+            |a, b| a.cmp(b),
+            |x| {
+                calls += 1;
+
+                if x != 3 {
+                    Ok(x * 10)
+                } else {
+                    Err(format!("Failed to process {}", x))
+                }
+            },
+        );
+
+        assert_eq!(merged, Err("Failed to process 3".to_string()));
+        assert_eq!(calls, 3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_try_map_hashed() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let mut expected = DefaultHasher::new();
+
+        4.hash(&mut expected);
+        8.hash(&mut expected);
+
+        let numbers: Vec<i32> = vec![2, 3, 4];
+        let mut iter = numbers
+            .into_iter()
+            .try_map_hashed(DefaultHasher::new(), |x| {
+                if x % 2 == 0 {
+                    Ok(x * 2)
+                } else {
+                    Err(format!("Failed to process {}", x))
+                }
+            });
+
+        let mapped: Vec<Result<i32, String>> = iter.by_ref().collect();
+
+        assert_eq!(
+            mapped,
+            vec![Ok(4), Err("Failed to process 3".to_string()), Ok(8)]
+        );
+        assert_eq!(iter.finish_hash(), expected.finish());
+
+        let numbers: Vec<i32> = vec![2, 4];
+        let mut iter = numbers
+            .into_iter()
+            .try_map_hashed(DefaultHasher::new(), |x| Ok::<_, String>(x * 2));
+
+        assert_eq!(iter.by_ref().count(), 2);
+        assert_eq!(iter.finish_hash(), expected.finish());
+    }
+
+    #[test]
+    fn test_try_map_or_value() {
+        let inputs: Vec<&str> = vec!["1", "x", "3", ""];
+        let values: Vec<i32> = inputs
+            .into_iter()
+            .try_map_or_value(-1, |s| s.parse::<i32>())
+            .collect();
+
+        assert_eq!(values, vec![1, -1, 3, -1]);
+    }
+
+    #[test]
+    fn test_try_map_or_else_item_recovers() {
+        let inputs: Vec<&str> = vec!["1", "", "3"];
+        let result: Result<Vec<i32>, String> = inputs
+            .into_iter()
+            .try_map_or_else_item(
+                |s| s.parse::<i32>().map_err(|_| s.to_string()),
+                |e| if e.is_empty() { Ok(0) } else { Err(e) },
+            )
+            .collect();
+
+        assert_eq!(result, Ok(vec![1, 0, 3]));
+    }
+
+    #[test]
+    fn test_try_map_or_else_item_recovery_fails() {
+        let inputs: Vec<&str> = vec!["1", "x", "3"];
+        let results: Vec<Result<i32, String>> = inputs
+            .into_iter()
+            .try_map_or_else_item(
+                |s| s.parse::<i32>().map_err(|_| s.to_string()),
+                |e| if e.is_empty() { Ok(0) } else { Err(e) },
+            )
+            .collect();
+
+        assert_eq!(results, vec![Ok(1), Err("x".to_string())]);
+    }
+
+    #[test]
+    fn test_rewindable_reset() {
+        let inputs = [1, 2, 3];
+        let mut iter = inputs
+            .iter()
+            .try_map(|&x| {
+                if x > 0 {
+                    Ok(x * 10)
+                } else {
+                    Err("non-positive")
+                }
+            })
+            .rewindable();
+
+        assert_eq!(iter.next(), Some(Ok(10)));
+        assert_eq!(iter.next(), Some(Ok(20)));
+
+        iter.reset();
+
+        let results: Result<Vec<i32>, &str> = iter.collect();
+
+        assert_eq!(results, Ok(vec![10, 20, 30]));
+    }
+
+    #[cfg(feature = "arrayvec")]
+    #[derive(Debug, PartialEq)]
+    enum ArrayVecError {
+        Parse,
+        Full(i32),
+    }
+
+    #[cfg(feature = "arrayvec")]
+    impl From<arrayvec::CapacityError<i32>> for ArrayVecError {
+        fn from(e: arrayvec::CapacityError<i32>) -> Self {
+            ArrayVecError::Full(e.element())
+        }
+    }
+
+    #[cfg(feature = "arrayvec")]
+    #[test]
+    fn test_try_collect_arrayvec_fits() {
+        let inputs = vec!["1", "2", "3"];
+        let values = inputs
+            .into_iter()
+            .try_collect_arrayvec::<4, _, _, _>(|s| {
+                s.parse::<i32>().map_err(|_| ArrayVecError::Parse)
+            })
+            .unwrap();
+
+        assert_eq!(values.as_slice(), &[1, 2, 3]);
+    }
+
+    #[cfg(feature = "arrayvec")]
+    #[test]
+    fn test_try_collect_arrayvec_overflows() {
+        let inputs = vec!["1", "2", "3"];
+        let result = inputs.into_iter().try_collect_arrayvec::<2, _, _, _>(|s| {
+            s.parse::<i32>().map_err(|_| ArrayVecError::Parse)
+        });
+
+        assert_eq!(
+            result.map(|values| values.len()),
+            Err(ArrayVecError::Full(3))
+        );
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn test_try_map_logged() {
+        use std::sync::Mutex;
+
+        static MESSAGES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+        struct TestLogger;
+
+        impl log::Log for TestLogger {
+            fn enabled(&self, metadata: &log::Metadata) -> bool {
+                metadata.level() <= log::Level::Error
+            }
+
+            fn log(&self, record: &log::Record) {
+                MESSAGES
+                    .lock()
+                    .unwrap()
+                    .push(format!("{}: {}", record.level(), record.args()));
+            }
+
+            fn flush(&self) {}
+        }
+
+        log::set_logger(&TestLogger).unwrap();
+        log::set_max_level(log::LevelFilter::Error);
+
+        let inputs = vec![1, 2, 3];
+        let result: Result<Vec<i32>, String> = inputs
+            .into_iter()
+            .try_map_logged(|x| {
+                if x != 2 {
+                    Ok(x)
+                } else {
+                    Err(format!("bad item {}", x))
+                }
+            })
+            .collect();
+
+        assert_eq!(result, Err("bad item 2".to_string()));
+        assert_eq!(
+            *MESSAGES.lock().unwrap(),
+            vec!["ERROR: bad item 2".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_try_map_iter() {
+        let some: Option<&str> = Some("4");
+        let values: Vec<i32> = some.try_map_iter(|s| s.parse::<i32>()).unwrap().collect();
+
+        assert_eq!(values, vec![4]);
+
+        let none: Option<&str> = None;
+
+        assert_eq!(none.try_map_iter(|s| s.parse::<i32>()).unwrap().count(), 0);
+
+        let bad: Option<&str> = Some("x");
+
+        assert!(bad.try_map_iter(|s| s.parse::<i32>()).is_err());
+    }
+
+    #[test]
+    fn test_try_map_progress() {
+        let mut counts = Vec::new();
+        let result: Result<Vec<i32>, String> = vec![1, 2, 3]
+            .into_iter()
+            .try_map_progress(|x| Ok(x * 2), |n| counts.push(n));
+
+        assert_eq!(result, Ok(vec![2, 4, 6]));
+        assert_eq!(counts, vec![1, 2, 3]);
+
+        let mut counts = Vec::new();
+        let result: Result<Vec<i32>, String> = vec![1, 2, 3, 4].into_iter().try_map_progress(
+            |x| {
+                if x != 3 {
+                    Ok(x)
+                } else {
+                    Err("three".to_string())
+                }
+            },
+            |n| counts.push(n),
+        );
+
+        assert_eq!(result, Err("three".to_string()));
+        assert_eq!(counts, vec![1, 2]);
+    }
+
+    #[cfg(feature = "either")]
+    #[test]
+    fn test_try_map_either() {
+        use either::Either;
+
+        let complete = vec![1, 2, 3].into_iter().try_map_either(|x| {
+            if x > 0 {
+                Ok(x * 2)
+            } else {
+                Err("non-positive")
+            }
+        });
+
+        assert_eq!(complete, Either::Left(vec![2, 4, 6]));
+
+        let partial = vec![1, 0, 3].into_iter().try_map_either(|x| {
+            if x > 0 {
+                Ok(x * 2)
+            } else {
+                Err("non-positive")
+            }
+        });
+
+        assert_eq!(partial, Either::Right((vec![2], "non-positive")));
+    }
+
+    #[test]
+    fn test_try_map_paged() {
+        let result: Result<Vec<Vec<i32>>, String> = (1..=5).try_map_paged(2, |x| Ok(x * 10));
+
+        assert_eq!(result, Ok(vec![vec![10, 20], vec![30, 40], vec![50]]));
+
+        let result: Result<Vec<Vec<i32>>, String> = (1..=4).try_map_paged(2, Ok);
+
+        assert_eq!(result, Ok(vec![vec![1, 2], vec![3, 4]]));
+
+        let result: Result<Vec<Vec<i32>>, String> = (1..=5).try_map_paged(2, |x| {
+            if x != 3 {
+                Ok(x)
+            } else {
+                Err("three".to_string())
+            }
+        });
+
+        assert_eq!(result, Err("three".to_string()));
+    }
+
+    #[test]
+    fn test_try_windows() {
+        let sums: Result<Vec<i32>, String> = vec![1, 2, 3, 4]
+            .into_iter()
+            .try_windows(2, |w| Ok(w.iter().sum()))
+            .collect();
+
+        assert_eq!(sums, Ok(vec![3, 5, 7]));
+        assert_eq!(
+            vec![1]
+                .into_iter()
+                .try_windows(2, |w: &[i32]| Ok::<_, String>(w[0]))
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_try_windows_with_ranges() {
+        let results: Vec<Result<(std::ops::Range<usize>, i32), String>> = vec![1, 2, -3, 4]
+            .into_iter()
+            .try_windows(2, |w| {
+                if w.iter().all(|&x| x > 0) {
+                    Ok(w.iter().sum())
+                } else {
+                    Err(format!("negative in {:?}", w))
+                }
+            })
+            .with_ranges()
+            .collect();
+
+        assert_eq!(
+            results,
+            vec![
+                Ok((0..2, 3)),
+                Err("negative in [2, -3]".to_string()),
+                Err("negative in [-3, 4]".to_string())
+            ]
+        );
+
+        let ranges: Vec<std::ops::Range<usize>> = (0..5)
+            .try_windows(3, |w| Ok::<_, String>(w.len()))
+            .with_ranges()
+            .map(|r| r.unwrap().0)
+            .collect();
+
+        assert_eq!(ranges, vec![0..3, 1..4, 2..5]);
+
+        let mut calls = 0;
+        let unfused = std::iter::from_fn(|| {
+            calls += 1;
+            if calls == 4 {
+                None
+            } else {
+                Some(calls)
+            }
+        });
+        let mut windows = unfused
+            .try_windows(2, |w| Ok::<_, String>(w.to_vec()))
+            .with_ranges();
+
+        assert_eq!(windows.next(), Some(Ok((0..2, vec![1, 2]))));
+        assert_eq!(windows.next(), Some(Ok((1..3, vec![2, 3]))));
+        assert_eq!(windows.next(), None);
+        assert_eq!(windows.next(), None);
+    }
+
+    #[test]
+    fn test_require() {
+        let present: Result<Option<i32>, String> = Ok(Some(1));
+        let missing: Result<Option<i32>, String> = Ok(None);
+        let failed: Result<Option<i32>, String> = Err("failed".to_string());
+
+        assert_eq!(present.require(|| "missing".to_string()), Ok(1));
+        assert_eq!(
+            missing.require(|| "missing".to_string()),
+            Err("missing".to_string())
+        );
+        assert_eq!(
+            failed.require(|| "missing".to_string()),
+            Err("failed".to_string())
+        );
+    }
+
+    #[test]
+    fn test_try_map_with_cleanup() {
+        let mut released = Vec::new();
+        let result: Result<Vec<i32>, String> = vec![1, 2, 3, 4].into_iter().try_map_with_cleanup(
+            |x| {
+                if x != 3 {
+                    Ok(x * 10)
+                } else {
+                    Err("three".to_string())
+                }
+            },
+            |x| released.push(*x),
+        );
+
+        assert_eq!(result, Err("three".to_string()));
+        assert_eq!(released, vec![10, 20]);
+
+        let mut released = Vec::new();
+        let result: Result<Vec<i32>, String> = vec![1, 2]
+            .into_iter()
+            .try_map_with_cleanup(|x| Ok(x * 10), |x| released.push(*x));
+
+        assert_eq!(result, Ok(vec![10, 20]));
+        assert!(released.is_empty());
+    }
+
+    #[test]
+    fn test_try_and_then_opt() {
+        let valid: Option<&str> = Some("4");
+        let invalid: Option<&str> = Some("x");
+        let missing: Option<&str> = None;
+
+        assert_eq!(
+            valid.try_and_then_opt(|s| s.parse::<i32>().ok()),
+            Ok::<_, String>(Some(4))
+        );
+        assert_eq!(
+            invalid.try_and_then_opt(|s| s.parse::<i32>().ok()),
+            Ok::<_, String>(None)
+        );
+        assert_eq!(
+            missing.try_and_then_opt(|s| s.parse::<i32>().ok()),
+            Ok::<_, String>(None)
+        );
+    }
+
+    #[test]
+    fn test_try_map_summarize() {
+        use fallible_map::TryMapSummary;
+
+        let summary = vec!["1", "2", "x", "4"]
+            .into_iter()
+            .try_map_summarize(|s| s.parse::<i32>().map_err(|_| s.to_string()));
+
+        assert_eq!(
+            summary,
+            TryMapSummary {
+                values: vec![1, 2],
+                error: Some("x".to_string()),
+                processed: 3
+            }
+        );
+
+        let summary = vec!["1", "2"]
+            .into_iter()
+            .try_map_summarize(|s| s.parse::<i32>().map_err(|_| s.to_string()));
+
+        assert_eq!(summary.values, vec![1, 2]);
+        assert_eq!(summary.error, None);
+        assert_eq!(summary.processed, 2);
+    }
+
+    #[test]
+    fn test_try_map_option_cow_str() {
+        use std::borrow::Cow;
+
+        let borrowed: Option<Cow<str>> = Some(Cow::Borrowed("42"));
+        let owned: Option<Cow<str>> = Some(Cow::Owned("7".to_string()));
+        let invalid: Option<Cow<str>> = Some(Cow::Borrowed("x"));
+        let missing: Option<Cow<str>> = None;
+
+        assert_eq!(
+            borrowed.try_map(|s| s.parse::<i32>().map_err(|_| s.into_owned())),
+            Ok(Some(42))
+        );
+        assert_eq!(
+            owned.try_map(|s| s.parse::<i32>().map_err(|_| s.into_owned())),
+            Ok(Some(7))
+        );
+        assert_eq!(
+            invalid.try_map(|s| s.parse::<i32>().map_err(|_| s.into_owned())),
+            Err("x".to_string())
+        );
+        assert_eq!(
+            missing.try_map(|s| s.parse::<i32>().map_err(|_| s.into_owned())),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn test_try_map_flatten_inner() {
+        let present: Option<Option<&str>> = Some(Some("4"));
+        let invalid: Option<Option<&str>> = Some(Some("x"));
+        let inner_none: Option<Option<&str>> = Some(None);
+        let outer_none: Option<Option<&str>> = None;
+
+        assert_eq!(
+            present.try_map_flatten_inner(|s| s.parse::<i32>()),
+            Ok(Some(4))
+        );
+        assert!(invalid.try_map_flatten_inner(|s| s.parse::<i32>()).is_err());
+        assert_eq!(
+            inner_none.try_map_flatten_inner(|s| s.parse::<i32>()),
+            Ok(None)
+        );
+        assert_eq!(
+            outer_none.try_map_flatten_inner(|s| s.parse::<i32>()),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn test_try_map_fn() {
+        fn parse(s: &str) -> Result<i32, String> {
+            s.parse::<i32>()
+                .map_err(|_| format!("invalid number: {}", s))
+        }
+
+        for input in [Some("4"), Some("x"), None] {
+            assert_eq!(
+                input.try_map_fn(parse),
+                input.try_map(|s| s
+                    .parse::<i32>()
+                    .map_err(|_| format!("invalid number: {}", s)))
+            );
+        }
+
+        assert_eq!(Some("4").try_map_fn(parse), Ok(Some(4)));
+    }
+
+    #[test]
+    fn test_try_map_f32_chunks() {
+        let values: Vec<f32> = (0..19).map(|x| x as f32).collect();
+        let result =
+            values.try_map_f32_chunks(|x| if x.is_nan() { Err("NaN") } else { Ok(x * 2.0) });
+
+        assert_eq!(
+            result,
+            Ok(values.iter().map(|x| x * 2.0).collect::<Vec<f32>>())
+        );
+
+        let mut calls = 0;
+        let mut values = values;
+
+        values[10] = f32::NAN;
+
+        let result = values.try_map_f32_chunks(|x| {
+            calls += 1;
+            if x.is_nan() {
+                Err("NaN")
+            } else {
+                Ok(x * 2.0)
+            }
+        });
+
+        assert_eq!(result, Err("NaN"));
+        assert_eq!(calls, 11);
+    }
+
+    #[test]
+    fn test_collapse_option_results() {
+        let inputs: Vec<Option<Result<i32, String>>> = vec![Some(Ok(1)), None, Some(Ok(3)), None];
+
+        assert_eq!(collapse_option_results(inputs), Ok(vec![1, 3]));
+
+        let mut visited = Vec::new();
+        let inputs: Vec<Option<Result<i32, String>>> =
+            vec![Some(Ok(1)), None, Some(Err("bad".to_string())), Some(Ok(4))];
+        let result =
+            collapse_option_results(inputs.into_iter().inspect(|x| visited.push(x.clone())));
+
+        assert_eq!(result, Err("bad".to_string()));
+        assert_eq!(visited.len(), 3);
+    }
+
+    #[test]
+    fn test_try_map_boxed() {
+        let source: Box<dyn Iterator<Item = &'static str>> =
+            Box::new(vec!["1", "2", "x"].into_iter());
+        let mut iter: Box<dyn Iterator<Item = Result<i32, String>>> =
+            source.try_map_boxed(|s| s.parse::<i32>().map_err(|_| s.to_string()));
+
+        assert_eq!(iter.next(), Some(Ok(1)));
+        assert_eq!(iter.next(), Some(Ok(2)));
+        assert_eq!(iter.next(), Some(Err("x".to_string())));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_try_map_send() {
+        use std::sync::mpsc;
+
+        let (tx, rx) = mpsc::channel();
+        let consumer = std::thread::spawn(move || rx.iter().collect::<Vec<i32>>());
+        let result = vec!["1", "2", "x", "4"]
+            .into_iter()
+            .try_map_send(tx, |s| s.parse::<i32>().map_err(|_| s.to_string()));
+
+        assert_eq!(result, Err("x".to_string()));
+        assert_eq!(consumer.join().unwrap(), vec![1, 2]);
+
+        let (tx, rx) = mpsc::channel();
+        let result = vec!["1", "2"]
+            .into_iter()
+            .try_map_send(tx, |s| s.parse::<i32>().map_err(|_| s.to_string()));
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(rx.iter().collect::<Vec<_>>(), vec![1, 2]);
+
+        let (tx, rx) = mpsc::channel();
+        let result = vec!["1", "x"].into_iter().try_map_send(tx, |s| {
+            s.parse::<i32>()
+                .map_err(|_| std::io::Error::new(std::io::ErrorKind::InvalidData, s.to_string()))
+        });
+
+        assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(rx.iter().collect::<Vec<i32>>(), vec![1]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_try_map_split_channels() {
+        use std::sync::mpsc;
+
+        let (ok_tx, ok_rx) = mpsc::channel();
+        let (err_tx, err_rx) = mpsc::channel();
+        let errors = std::thread::spawn(move || err_rx.iter().collect::<Vec<String>>());
+
+        vec!["1", "x", "3", "y"]
+            .into_iter()
+            .try_map_split_channels(ok_tx, err_tx, |s| {
+                s.parse::<i32>().map_err(|_| s.to_string())
+            });
+
+        assert_eq!(ok_rx.iter().collect::<Vec<i32>>(), vec![1, 3]);
+        assert_eq!(
+            errors.join().unwrap(),
+            vec!["x".to_string(), "y".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_try_map_tally() {
+        let tally = vec!["1", "x", "3", "y", "5"]
+            .into_iter()
+            .try_map_tally(|s| s.parse::<i32>());
+
+        assert_eq!(tally, (3, 2));
+        assert_eq!(
+            Vec::<&str>::new()
+                .into_iter()
+                .try_map_tally(|s| s.parse::<i32>()),
+            (0, 0)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_try_map_backoff() {
+        use std::time::{Duration, Instant};
+
+        let mut attempts = 0;
+        let start = Instant::now();
+        let result: Result<Vec<i32>, String> = vec![1]
+            .into_iter()
+            .try_map_backoff(Duration::from_millis(5), 3, |&x| {
+                attempts += 1;
+                if attempts < 3 {
+                    Err(format!("attempt {}", attempts))
+                } else {
+                    Ok(x * 10)
+                }
+            })
+            .collect();
+
+        assert_eq!(result, Ok(vec![10]));
+        assert_eq!(attempts, 3);
+        assert!(start.elapsed() >= Duration::from_millis(15));
+
+        let mut attempts = 0;
+        let result: Result<Vec<i32>, String> = vec![1]
+            .into_iter()
+            .try_map_backoff(Duration::from_millis(1), 2, |_| {
+                attempts += 1;
+                Err(format!("attempt {}", attempts))
+            })
+            .collect();
+
+        assert_eq!(result, Err("attempt 3".to_string()));
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_try_map_traced() {
+        use std::sync::{Arc, Mutex};
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata, Subscriber};
+
+        struct Fields(Vec<String>);
+
+        impl Visit for Fields {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                self.0.push(format!("{}={:?}", field.name(), value));
+            }
+        }
+
+        struct TestSubscriber(Arc<Mutex<Vec<String>>>);
+
+        impl Subscriber for TestSubscriber {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                let mut fields = Fields(Vec::new());
+
+                span.record(&mut fields);
+                self.0.lock().unwrap().push(format!(
+                    "span {} {}",
+                    span.metadata().name(),
+                    fields.0.join(" ")
+                ));
+
+                Id::from_u64(1)
+            }
+
+            fn record(&self, _: &Id, _: &Record<'_>) {}
+
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+            fn event(&self, event: &Event<'_>) {
+                let mut fields = Fields(Vec::new());
+
+                event.record(&mut fields);
+                self.0.lock().unwrap().push(format!(
+                    "{} {}",
+                    event.metadata().level(),
+                    fields.0.join(" ")
+                ));
+            }
+
+            fn enter(&self, _: &Id) {}
+
+            fn exit(&self, _: &Id) {}
+        }
+
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let result: Vec<Result<i32, String>> =
+            tracing::subscriber::with_default(TestSubscriber(records.clone()), || {
+                vec!["1", "x"]
+                    .into_iter()
+                    .try_map_traced("parse", |s| {
+                        s.parse::<i32>().map_err(|_| format!("invalid {}", s))
+                    })
+                    .collect()
+            });
+
+        assert_eq!(result, vec![Ok(1), Err("invalid x".to_string())]);
+        assert_eq!(
+            *records.lock().unwrap(),
+            vec![
+                "span try_map name=\"parse\" index=0".to_string(),
+                "DEBUG message=item mapped".to_string(),
+                "span try_map name=\"parse\" index=1".to_string(),
+                "ERROR message=item failed to map error=invalid x".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_split_at_error() {
+        let (values, error) = vec!["1", "2", "x", "4"]
+            .into_iter()
+            .try_map(|s| s.parse::<i32>().map_err(|_| s.to_string()))
+            .split_at_error();
+
+        assert_eq!(values.collect::<Vec<i32>>(), vec![1, 2]);
+        assert_eq!(error(), Some("x".to_string()));
+
+        let (values, error) = vec!["1", "2"]
+            .into_iter()
+            .try_map(|s| s.parse::<i32>().map_err(|_| s.to_string()))
+            .split_at_error();
+
+        assert_eq!(values.sum::<i32>(), 3);
+        assert_eq!(error(), None);
+    }
+
+    #[test]
+    fn test_try_map_cloned() {
+        let words: Vec<String> = vec!["alpha".to_string(), "beta".to_string()];
+        let by_ref: Result<Vec<usize>, String> = words
+            .iter()
+            .try_map(|s: &String| {
+                if s.is_empty() {
+                    Err("empty".to_string())
+                } else {
+                    Ok(s.len())
+                }
+            })
+            .collect();
+
+        assert_eq!(by_ref, Ok(vec![5, 4]));
+
+        let owned: Result<Vec<String>, String> = words
+            .as_slice()
+            .iter()
+            .try_map_cloned(|mut s: String| {
+                if s.is_empty() {
+                    Err("empty".to_string())
+                } else {
+                    s.push('!');
+                    Ok(s)
+                }
+            })
+            .collect();
+
+        assert_eq!(owned, Ok(vec!["alpha!".to_string(), "beta!".to_string()]));
+        assert_eq!(words, vec!["alpha".to_string(), "beta".to_string()]);
+    }
+
+    #[test]
+    fn test_try_map_classified() {
+        let parse = |s: &str| s.parse::<i32>().map_err(|_| s.to_string());
+        let is_fatal = |e: &String| e == "fatal";
+
+        let result = vec!["1", "x", "3", "y"]
+            .into_iter()
+            .try_map_classified(parse, is_fatal);
+
+        assert_eq!(
+            result,
+            Ok((vec![1, 3], vec!["x".to_string(), "y".to_string()]))
+        );
+
+        let result = vec!["1", "x", "fatal", "4"]
+            .into_iter()
+            .try_map_classified(parse, is_fatal);
+
+        assert_eq!(result, Err("fatal".to_string()));
+    }
+
+    #[test]
+    fn test_try_map_error_windows() {
+        let windows: Vec<_> = vec!["1", "2", "x", "4", "y", "z", "7"]
+            .into_iter()
+            .try_map_error_windows(3, |s| s.parse::<i32>().map_err(|_| s.to_string()))
+            .collect();
+
+        assert_eq!(
+            windows,
+            vec![
+                Err((vec![1, 2], vec!["x".to_string()])),
+                Err((vec![4], vec!["y".to_string(), "z".to_string()])),
+                Ok(vec![7])
+            ]
+        );
+
+        let windows: Vec<_> = vec!["1", "2", "3", "4"]
+            .into_iter()
+            .try_map_error_windows(2, |s| s.parse::<i32>().map_err(|_| s.to_string()))
+            .collect();
+
+        assert_eq!(windows, vec![Ok(vec![1, 2]), Ok(vec![3, 4])]);
+    }
+
+    #[test]
+    fn test_try_contains() {
+        let normalize = |s: &&str| {
+            s.trim()
+                .parse::<i32>()
+                .map(|x| x == 42)
+                .map_err(|_| s.to_string())
+        };
+
+        assert_eq!(None::<&str>.try_contains(normalize), Ok(false));
+        assert_eq!(Some(" 42 ").try_contains(normalize), Ok(true));
+        assert_eq!(Some("7").try_contains(normalize), Ok(false));
+        assert_eq!(Some("x").try_contains(normalize), Err("x".to_string()));
+    }
+
+    #[test]
+    fn test_try_map_into_slice() {
+        let mut out = [0; 3];
+        let mut calls = 0;
+        let written = (1..=5).try_map_into_slice(&mut out, |x| {
+            calls += 1;
+            Ok::<_, String>(x * 10)
+        });
+
+        assert_eq!(written, Ok(3));
+        assert_eq!(out, [10, 20, 30]);
+        assert_eq!(calls, 3);
+
+        let mut out = [0; 4];
+        let written = (1..=2).try_map_into_slice(&mut out, |x| Ok::<_, String>(x * 10));
+
+        assert_eq!(written, Ok(2));
+        assert_eq!(out, [10, 20, 0, 0]);
+
+        let mut out = [0; 4];
+        let written = (1..=4).try_map_into_slice(&mut out, |x| {
+            if x != 3 {
+                Ok(x * 10)
+            } else {
+                Err("three".to_string())
+            }
+        });
+
+        assert_eq!(written, Err("three".to_string()));
+        assert_eq!(out, [10, 20, 0, 0]);
+    }
+
+    #[test]
+    fn test_try_map_roundtrip() {
+        use fallible_map::RoundtripError;
+
+        let to = |x: &i32| Ok::<_, String>(x.to_string());
+        let from = |s: String| s.parse::<i32>().map_err(|e| e.to_string());
+
+        assert_eq!(Some(42).try_map_roundtrip(to, from), Ok(Some(42)));
+        assert_eq!(None.try_map_roundtrip(to, from), Ok(None));
+
+        let lossy = |x: &i32| Ok::<_, String>((x / 10).to_string());
+
+        assert_eq!(
+            Some(42).try_map_roundtrip(lossy, from),
+            Err(RoundtripError::Mismatch)
+        );
+
+        let broken = |x: &i32| Ok::<_, String>(format!("{}x", x));
+
+        assert!(matches!(
+            Some(42).try_map_roundtrip(broken, from),
+            Err(RoundtripError::Mapper(_))
+        ));
+    }
+
+    #[test]
+    fn test_try_map_until() {
+        use fallible_map::Step;
+
+        let step = |x: i32| match x {
+            0 => Err("zero".to_string()),
+            x if x < 5 => Ok(Step::Yield(x * 10)),
+            5 => Ok(Step::YieldAndStop(x * 10)),
+            _ => Ok(Step::Stop),
+        };
+
+        let results: Vec<Result<i32, String>> =
+            vec![1, 0, 2].into_iter().try_map_until(step).collect();
+
+        assert_eq!(results, vec![Ok(10), Err("zero".to_string()), Ok(20)]);
+
+        let results: Vec<Result<i32, String>> =
+            vec![1, 5, 2].into_iter().try_map_until(step).collect();
+
+        assert_eq!(results, vec![Ok(10), Ok(50)]);
+
+        let results: Vec<Result<i32, String>> =
+            vec![1, 9, 2].into_iter().try_map_until(step).collect();
+
+        assert_eq!(results, vec![Ok(10)]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_try_map_dedup_persist() {
+        use std::collections::HashSet;
+
+        let mut seen: HashSet<&str> = HashSet::new();
+
+        seen.insert("1");
+
+        let results: Vec<Result<i32, String>> = vec!["1", "2", "x", "2", "x", "3"]
+            .into_iter()
+            .try_map_dedup_persist(&mut seen, |s| s.parse::<i32>().map_err(|_| s.to_string()))
+            .collect();
+
+        assert_eq!(
+            results,
+            vec![Ok(2), Err("x".to_string()), Err("x".to_string()), Ok(3)]
+        );
+        assert_eq!(
+            seen,
+            vec!["1", "2", "3"].into_iter().collect::<HashSet<_>>()
+        );
+
+        let results: Vec<Result<i32, String>> = vec!["2", "3", "4"]
+            .into_iter()
+            .try_map_dedup_persist(&mut seen, |s| s.parse::<i32>().map_err(|_| s.to_string()))
+            .collect();
+
+        assert_eq!(results, vec![Ok(4)]);
+    }
+
+    #[test]
+    fn test_try_map_with_attempts() {
+        let mut failures = std::collections::HashMap::new();
+
+        failures.insert(2, 2);
+        failures.insert(3, 5);
+
+        let results: Vec<Result<(i32, usize), String>> = vec![1, 2, 3]
+            .into_iter()
+            .try_map_with_attempts(3, |x| {
+                let remaining = failures.entry(*x).or_insert(0);
+
+                if *remaining > 0 {
+                    *remaining -= 1;
+                    Err(format!("flaky {}", x))
+                } else {
+                    Ok(x * 10)
+                }
+            })
+            .collect();
+
+        assert_eq!(
+            results,
+            vec![Ok((10, 1)), Ok((20, 3)), Err("flaky 3".to_string())]
+        );
+    }
+
+    #[cfg(all(feature = "std", unix))]
+    #[test]
+    fn test_try_map_command() {
+        use std::process::Command;
+
+        let outputs = vec!["hello", "world"]
+            .into_iter()
+            .try_map_command(|word| {
+                let mut command = Command::new("echo");
+
+                command.arg(word);
+
+                Ok::<_, std::io::Error>(command)
+            })
+            .unwrap();
+
+        let stdout: Vec<String> = outputs
+            .into_iter()
+            .map(|output| String::from_utf8(output.stdout).unwrap())
+            .collect();
+
+        assert_eq!(stdout, vec!["hello\n".to_string(), "world\n".to_string()]);
+
+        let result = vec!["missing"]
+            .into_iter()
+            .try_map_command(|_| Ok::<_, std::io::Error>(Command::new("/nonexistent/command")));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_try_map_dry_run() {
+        let mut calls = 0;
+        let result: Result<Vec<Option<i32>>, String> =
+            vec![1, 2, 3].into_iter().try_map_dry_run(true, |x| {
+                calls += 1;
+                Ok(x * 10)
+            });
+
+        assert_eq!(result, Ok(vec![None, None, None]));
+        assert_eq!(calls, 0);
+
+        let result: Result<Vec<Option<i32>>, String> = vec![1, 2, 3]
+            .into_iter()
+            .try_map_dry_run(false, |x| Ok(x * 10));
+
+        assert_eq!(result, Ok(vec![Some(10), Some(20), Some(30)]));
+
+        let result: Result<Vec<Option<i32>>, String> =
+            vec![1, 2, 3].into_iter().try_map_dry_run(false, |x| {
+                if x != 2 {
+                    Ok(x)
+                } else {
+                    Err("two".to_string())
+                }
+            });
+
+        assert_eq!(result, Err("two".to_string()));
+    }
+
+    #[test]
+    fn test_try_map_with_rng() {
+        struct FakeRng(u64);
+
+        impl FakeRng {
+            fn next_u64(&mut self) -> u64 {
+                self.0 = self
+                    .0
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                self.0 >> 33
+            }
+        }
+
+        let run = |seed| {
+            vec![10, 20, 30]
+                .into_iter()
+                .try_map_with_rng(&mut FakeRng(seed), |rng, x| {
+                    Ok::<_, String>(x + rng.next_u64() % 5)
+                })
+        };
+
+        assert_eq!(run(42), run(42));
+        assert_eq!(run(42).unwrap().len(), 3);
+
+        let mut rng = FakeRng(7);
+        let result: Result<Vec<u64>, String> =
+            vec![1, 2].into_iter().try_map_with_rng(&mut rng, |_, x| {
+                if x != 2 {
+                    Ok(x)
+                } else {
+                    Err("two".to_string())
+                }
+            });
+
+        assert_eq!(result, Err("two".to_string()));
+    }
+
+    #[test]
+    fn test_try_map_vec() {
+        let missing: Option<Vec<&str>> = None;
+        let valid: Option<Vec<&str>> = Some(vec!["1", "2", "3"]);
+        let invalid: Option<Vec<&str>> = Some(vec!["1", "x", "3"]);
+
+        assert_eq!(
+            missing.try_map_vec(|s| s.parse::<i32>().map_err(|_| s.to_string())),
+            Ok(None)
+        );
+        assert_eq!(
+            valid.try_map_vec(|s| s.parse::<i32>().map_err(|_| s.to_string())),
+            Ok(Some(vec![1, 2, 3]))
+        );
+        assert_eq!(
+            invalid.try_map_vec(|s| s.parse::<i32>().map_err(|_| s.to_string())),
+            Err("x".to_string())
+        );
+    }
+
+    #[test]
+    fn test_try_interleave() {
+        let parse = |s: &str| s.parse::<i32>().map_err(|_| s.to_string());
+        let other = vec!["10", "x"].into_iter().map(parse);
+        let results: Vec<Result<i32, String>> = vec!["1", "2", "3", "4"]
+            .into_iter()
+            .try_map(parse)
+            .try_interleave(other)
+            .collect();
+
+        assert_eq!(
+            results,
+            vec![Ok(1), Ok(10), Ok(2), Err("x".to_string()), Ok(3), Ok(4)]
+        );
+
+        let other = vec!["10", "20", "30"].into_iter().map(parse);
+        let results: Vec<Result<i32, String>> = vec!["1"]
+            .into_iter()
+            .try_map(parse)
+            .try_interleave(other)
+            .collect();
+
+        assert_eq!(results, vec![Ok(1), Ok(10), Ok(20), Ok(30)]);
+    }
+
+    #[test]
+    fn test_try_collect_with_remaining() {
+        let (result, remaining) = vec![1, 2, 3, 4, 5]
+            .into_iter()
+            .try_collect_with_remaining(|x| {
+                if x != 2 {
+                    Ok(x)
+                } else {
+                    Err("two".to_string())
+                }
+            });
+
+        assert_eq!(result, Err("two".to_string()));
+        assert_eq!(remaining, 3);
+
+        let (result, remaining) = vec![1, 2]
+            .into_iter()
+            .try_collect_with_remaining(|x| Ok::<_, String>(x * 10));
+
+        assert_eq!(result, Ok(vec![10, 20]));
+        assert_eq!(remaining, 0);
+    }
+
+    #[test]
+    fn test_try_map_value() {
+        let present: (&str, Option<&str>) = ("port", Some("8080"));
+        let missing: (&str, Option<&str>) = ("host", None);
+        let invalid: (&str, Option<&str>) = ("port", Some("x"));
+
+        assert_eq!(
+            present.try_map_value(|s| s.parse::<u16>()),
+            Ok(("port", Some(8080)))
+        );
+        assert_eq!(
+            missing.try_map_value(|s| s.parse::<u16>()),
+            Ok(("host", None))
+        );
+        assert!(invalid.try_map_value(|s| s.parse::<u16>()).is_err());
+    }
+
+    #[cfg(feature = "indexmap")]
+    #[test]
+    fn test_indexmap_try_map_values() {
+        use fallible_map::collections::FallibleIndexMapExt;
+        use indexmap::IndexMap;
+
+        let map: IndexMap<&str, &str> = vec![("c", "3"), ("a", "1"), ("b", "2")]
+            .into_iter()
+            .collect();
+        let mapped = map
+            .try_map_values(|s| s.parse::<i32>().map_err(|_| s.to_string()))
+            .unwrap();
+
+        assert_eq!(
+            mapped.into_iter().collect::<Vec<_>>(),
+            vec![("c", 3), ("a", 1), ("b", 2)]
+        );
+
+        let map: IndexMap<&str, &str> = vec![("c", "3"), ("a", "x"), ("b", "y")]
+            .into_iter()
+            .collect();
+
+        assert_eq!(
+            map.try_map_values(|s| s.parse::<i32>().map_err(|_| s.to_string())),
+            Err("x".to_string())
+        );
+    }
+
+    #[test]
+    fn test_try_map_deferred() {
+        let (values, error) = vec!["1", "2", "x", "4"]
+            .into_iter()
+            .try_map_deferred(|s| s.parse::<i32>().map_err(|_| s.to_string()));
+
+        assert!(!error.is_err());
+        assert_eq!(values.collect::<Vec<i32>>(), vec![1, 2]);
+        assert!(error.is_err());
+        assert_eq!(error.take(), Some("x".to_string()));
+        assert_eq!(error.take(), None);
+
+        let (values, error) = vec!["1", "2"]
+            .into_iter()
+            .try_map_deferred(|s| s.parse::<i32>().map_err(|_| s.to_string()));
+
+        assert_eq!(values.count(), 2);
+        assert_eq!(error.take(), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_try_map_unique() {
+        let parse = |s: &str| {
+            s.split_once('=')
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .ok_or_else(|| format!("invalid entry {}", s))
+        };
+
+        let result = vec!["a=1", "b=2"].into_iter().try_map_unique(
+            |(k, _): &(String, String)| k.clone(),
+            parse,
+            |k| format!("duplicate key {}", k),
+        );
+
+        assert_eq!(
+            result,
+            Ok(vec![
+                ("a".to_string(), "1".to_string()),
+                ("b".to_string(), "2".to_string())
+            ])
+        );
+
+        let result = vec!["a=1", "b=2", "a=3"].into_iter().try_map_unique(
+            |(k, _): &(String, String)| k.clone(),
+            parse,
+            |k| format!("duplicate key {}", k),
+        );
+
+        assert_eq!(result, Err("duplicate key a".to_string()));
+
+        let result = vec!["a=1", "b"].into_iter().try_map_unique(
+            |(k, _): &(String, String)| k.clone(),
+            parse,
+            |k| format!("duplicate key {}", k),
+        );
+
+        assert_eq!(result, Err("invalid entry b".to_string()));
+    }
+
+    #[test]
+    fn test_try_map_or_abort() {
+        let parse = |s: &str| s.parse::<i32>().map_err(|_| format!("invalid {}", s));
+
+        assert_eq!(
+            Some("4").try_map_or_abort(parse, || "missing".to_string()),
+            Ok(4)
+        );
+        assert_eq!(
+            Some("x").try_map_or_abort(parse, || "missing".to_string()),
+            Err("invalid x".to_string())
+        );
+        assert_eq!(
+            None.try_map_or_abort(parse, || "missing".to_string()),
+            Err("missing".to_string())
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_try_map_flush_every() {
+        use std::io::{self, Write};
+
+        #[derive(Default)]
+        struct CountingWriter {
+            written: Vec<u8>,
+            flushes: Vec<usize>,
+        }
+
+        impl Write for CountingWriter {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.written.extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                self.flushes.push(self.written.len());
+                Ok(())
+            }
+        }
+
+        let mut writer = CountingWriter::default();
+        let result: Result<(), io::Error> = vec!["a", "b", "c", "d", "e"]
+            .into_iter()
+            .try_map_flush_every(&mut writer, 2, |s| Ok(s.as_bytes()));
+
+        assert!(result.is_ok());
+        assert_eq!(writer.written, b"abcde".to_vec());
+        assert_eq!(writer.flushes, vec![2, 4, 5]);
+
+        let mut writer = CountingWriter::default();
+        let result: Result<(), io::Error> =
+            vec!["a", "b", ""]
+                .into_iter()
+                .try_map_flush_every(&mut writer, 1, |s| {
+                    if s.is_empty() {
+                        Err(io::Error::new(io::ErrorKind::InvalidData, "empty"))
+                    } else {
+                        Ok(s.as_bytes())
+                    }
+                });
+
+        assert!(result.is_err());
+        assert_eq!(writer.flushes, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_try_map_into_result() {
+        struct Checked(Result<i32, String>);
+
+        impl From<Checked> for Result<i32, String> {
+            fn from(checked: Checked) -> Self {
+                checked.0
+            }
+        }
+
+        let check = |x: i32| {
+            Checked(if x > 0 {
+                Ok(x * 2)
+            } else {
+                Err(format!("non-positive {}", x))
+            })
+        };
+
+        assert_eq!(Some(2).try_map_into_result(check), Ok(Some(4)));
+        assert_eq!(
+            Some(-1).try_map_into_result(check),
+            Err("non-positive -1".to_string())
+        );
+        assert_eq!(None.try_map_into_result(check), Ok(None));
+        assert_eq!(
+            Some("4").try_map_into_result(|s| s.parse::<i32>()),
+            Ok(Some(4))
+        );
+    }
+
+    #[test]
+    fn test_try_map_first_errors() {
+        let mut calls = 0;
+        let result = vec!["a", "1", "b", "c", "d"]
+            .into_iter()
+            .try_map_first_errors(2, |s| {
+                calls += 1;
+                s.parse::<i32>().map_err(|_| s.to_string())
+            });
+
+        assert_eq!(result, Err(vec!["a".to_string(), "b".to_string()]));
+        assert_eq!(calls, 3);
+
+        let result = vec!["1", "a", "3"]
+            .into_iter()
+            .try_map_first_errors(5, |s| s.parse::<i32>().map_err(|_| s.to_string()));
+
+        assert_eq!(result, Err(vec!["a".to_string()]));
+
+        let result = vec!["1", "2"]
+            .into_iter()
+            .try_map_first_errors(5, |s| s.parse::<i32>().map_err(|_| s.to_string()));
+
+        assert_eq!(result, Ok(vec![1, 2]));
+    }
+
+    #[test]
+    fn test_try_map_typed_errors() {
+        use fallible_map::TypedError;
+
+        let results: Vec<Result<i32, TypedError<String>>> = vec![1u8, 0, 3]
+            .into_iter()
+            .try_map_typed_errors(|x| {
+                if x > 0 {
+                    Ok(i32::from(x) * 10)
+                } else {
+                    Err("zero".to_string())
+                }
+            })
+            .collect();
+
+        assert_eq!(
+            results,
+            vec![
+                Ok(10),
+                Err(TypedError::new("u8", "zero".to_string())),
+                Ok(30)
+            ]
+        );
+
+        let error = vec!["x"]
+            .into_iter()
+            .try_map_typed_errors(|s| s.parse::<i32>().map_err(|_| s.to_string()))
+            .next()
+            .unwrap()
+            .unwrap_err();
+
+        assert_eq!(error.type_name, std::any::type_name::<&str>());
+        assert_eq!(error.to_string(), "failed to map item of type &str: x");
+    }
 }